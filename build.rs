@@ -0,0 +1,107 @@
+//! Generates `MaterialSymbol` enum from `src/icons/codepoints`.
+//!
+//! The file has Material Symbols codepoints format: one `name codepoint` pair per line, e.g. `search e8b6`,
+//! lines starting with `#` are comments.
+//! If `MATERIAL_SYMBOLS_CODEPOINTS` environment variable is set, the file at that path is used instead,
+//! e.g. the full upstream `.codepoints` file downloaded next to the app.
+//!
+//! With `svg-icons` feature also generates the table of inline SVG icons from `src/icons/svg_paths`:
+//! one `name fill wght path` line per variant, lines starting with `#` are comments.
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const CODEPOINTS: &str = "src/icons/codepoints";
+const SVG_PATHS: &str = "src/icons/svg_paths";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=MATERIAL_SYMBOLS_CODEPOINTS");
+    let codepoints_path = env::var("MATERIAL_SYMBOLS_CODEPOINTS").unwrap_or_else(|_| CODEPOINTS.to_string());
+    println!("cargo:rerun-if-changed={codepoints_path}");
+    let codepoints = fs::read_to_string(&codepoints_path)
+        .unwrap_or_else(|err| panic!("Failed to read Material Symbols codepoints from {codepoints_path}: {err}"));
+
+    let mut names = HashSet::new();
+    let mut symbols = Vec::new();
+    for line in codepoints.lines().filter(|line| !line.starts_with('#')) {
+        let Some((name, codepoint)) = line.trim().split_once(' ') else {
+            continue;
+        };
+        let variant = variant_name(name);
+        if names.insert(variant.clone()) {
+            symbols.push((name.to_string(), variant, codepoint.trim().to_string()));
+        }
+    }
+
+    let mut code = String::new();
+    code.push_str("#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]\npub enum MaterialSymbol {\n");
+    for (name, variant, _) in &symbols {
+        writeln!(code, "    /// `{name}`\n    {variant},").unwrap();
+    }
+    code.push_str("}\n\nimpl MaterialSymbol {\n    pub const ALL: &'static [MaterialSymbol] = &[\n");
+    for (_, variant, _) in &symbols {
+        writeln!(code, "        MaterialSymbol::{variant},").unwrap();
+    }
+    code.push_str("    ];\n\n    /// Ligature of the icon in Material Symbols fonts, e.g. `search`\n    pub fn name(&self) -> &'static str {\n        match self {\n");
+    for (name, variant, _) in &symbols {
+        writeln!(code, "            MaterialSymbol::{variant} => \"{name}\",").unwrap();
+    }
+    code.push_str("        }\n    }\n\n    pub fn codepoint(&self) -> char {\n        match self {\n");
+    for (_, variant, codepoint) in &symbols {
+        writeln!(code, "            MaterialSymbol::{variant} => '\\u{{{codepoint}}}',").unwrap();
+    }
+    code.push_str("        }\n    }\n}\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("material_symbols.rs");
+    fs::write(out, code).expect("Failed to write MaterialSymbol enum");
+
+    if env::var_os("CARGO_FEATURE_SVG_ICONS").is_some() {
+        svg_paths();
+    }
+}
+
+fn svg_paths() {
+    println!("cargo:rerun-if-changed={SVG_PATHS}");
+    let paths = fs::read_to_string(SVG_PATHS).expect("Failed to read SVG icon paths");
+
+    let mut code = String::from("/// `(name, fill, wght, path)`, `None` fill is used for both filled and not filled icon\nconst SVG_PATHS: &[(&str, Option<bool>, u16, &str)] = &[\n");
+    for line in paths.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let mut parts = line.splitn(4, ' ');
+        let (Some(name), Some(fill), Some(wght), Some(path)) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            panic!("Wrong line in {SVG_PATHS}: {line}");
+        };
+        let fill = match fill {
+            "0" => "Some(false)",
+            "1" => "Some(true)",
+            "*" => "None",
+            _ => panic!("Wrong fill {fill} of {name} in {SVG_PATHS}, it must be 0, 1 or *"),
+        };
+        let wght: u16 = wght.parse().unwrap_or_else(|_| panic!("Wrong wght {wght} of {name} in {SVG_PATHS}"));
+        writeln!(code, "    (\"{name}\", {fill}, {wght}, \"{}\"),", path.trim()).unwrap();
+    }
+    code.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("svg_paths.rs");
+    fs::write(out, code).expect("Failed to write SVG icon paths");
+}
+
+/// `arrow_back` -> `ArrowBack`, `3d_rotation` -> `Icon3dRotation`
+fn variant_name(name: &str) -> String {
+    let mut variant: String = name
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.insert_str(0, "Icon");
+    }
+    variant
+}
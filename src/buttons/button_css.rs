@@ -1,10 +1,7 @@
-use std::str::FromStr;
-
-use color_art::Color;
 use stylist::Style;
 use yew::prelude::*;
 
-use crate::GButtonStyle;
+use crate::{theme::{density_size, sys_color, GDensity, GElevation, GState, GDuration, GEasing, GShape, GShapeCorner, GStateLayer, GTypescale, FORCED_COLORS_MEDIA, REDUCED_MOTION_MEDIA}, GButtonStyle};
 
 use super::DependsOn;
 
@@ -16,15 +13,15 @@ pub(super) fn input_style(
     g_init: &AttrValue,
     mut font_size: AttrValue,
     mut height: AttrValue,
+    density: Option<GDensity>,
     width: &Option<AttrValue>,
     background_color: &AttrValue,
     mut text_color: AttrValue,
-    outlined_border_color: &Option<AttrValue>,
-    mut border_radius: AttrValue,
+    outlined_border_color: &AttrValue,
+    mut shape: GShape,
     disabled: bool,
     has_icon: bool,
     trailing_icon: bool,
-    dark_theame: bool,
     parent: &DependsOn,
 ) -> Style {
     let mut icon_font_size = 1.29;
@@ -33,108 +30,94 @@ pub(super) fn input_style(
         font_size = AttrValue::from("inherit");
         icon_font_size = 1.5;
         height = AttrValue::from("2.5em");
-        border_radius = AttrValue::from("50%");
+        shape = GShape::from(GShapeCorner::Full);
     }
-    let button_background_color = Color::from_str(&background_color).unwrap();
+    let button_background_color = match style {
+        GButtonStyle::Elevated => GElevation::Level1.tint_var(background_color),
+        _ => background_color.clone(),
+    };
 
     let width: AttrValue = if width.is_some() { 
         AttrValue::from(format!("width: {};", width.clone().unwrap()))
      } else { AttrValue::default() };
-     let padding_left: String;
-     let padding_right: String;
-     let mut icon_align = "1.14em".to_string();
+     // paddings shrink by 4px and the icon offset by 2px for every density step, like the height
+     let padding_start: String;
+     let padding_end: String;
+     let padding = density_size("1.71em", "0.2857em", density).to_string();
+     let icon_padding = density_size("3em", "0.2857em", density).to_string();
+     let padding_block = density_size("0.7em", "0.1429em", density);
+     let mut icon_align = density_size("1.14em", "0.1429em", density).to_string();
      if has_icon && !only_icon {
         if trailing_icon {
-            padding_left = "1.71em".to_string();
-            padding_right = "3em".to_string();
+            padding_start = padding;
+            padding_end = icon_padding;
         } else {
-            padding_left = "3em".to_string();
-            padding_right = "1.71em".to_string();
+            padding_start = icon_padding;
+            padding_end = padding;
         }
      } else if !only_icon {
-        padding_left = "1.71em".to_string();
-        padding_right = "1.71em".to_string();
+        padding_start = padding.clone();
+        padding_end = padding;
      } else {
-        let (height, height_text) = crate::parse_number_and_unit(height.clone());
-        padding_left = format!("{}{}", height / 2.0, height_text);
-        padding_right = padding_left.clone();
-        icon_align = format!("{}{}", (height - icon_font_size) / 2.0, height_text);
+        padding_start = format!("calc({height} / 2)");
+        padding_end = padding_start.clone();
+        icon_align = format!("calc(({height} - {icon_font_size}em) / 2)");
      }
     
-    let bsc: i16 = if dark_theame { 255 } else { 0 };
+    let label_large = GTypescale::LabelLarge;
+    let font_family = label_large.var("font");
+    let font_weight = label_large.var("weight");
+    let letter_spacing = label_large.var("tracking");
+    let on_surface = GStateLayer::new(&sys_color("on-surface"));
+    let disabled_container_color = on_surface.with_opacity(0.12);
+    let state_layer = GStateLayer::new(&text_color);
     let mut hover_shadow: String = String::new();
     let mut active_shadow: String = String::new();
     let background_color: String;
-    let mut background_color_animation_from: String = String::new();
-    let mut background_color_animation_to: String = String::new();
-    let mut button_background_color_on_hover = String::new();
+    let mut background_color_on_hover = String::new();
+    let mut background_color_on_focus = String::new();
+    let mut ripple_color = String::new();
     let mut outlined_border_color_set: String = String::new();
     match style {
-        GButtonStyle::Elevated => {
-            if !disabled {
-                let background_color_animation: Color;
-                if button_background_color.lightness() > 0.5 {
-                    button_background_color_on_hover = format!("background-color: {};", button_background_color.shade(0.9));                    
-                    background_color_animation = Color::new(0, 0, 0, 1.0);
-                } else {
-                    button_background_color_on_hover = format!("background-color: {};", button_background_color.tint(0.9));                    
-                    background_color_animation = Color::new(255, 255, 255, 1.0);
-                }
-                hover_shadow = format!("box-shadow: 0 3px 6px rgba({bsc},{bsc},{bsc},0.16), 0 3px 6px rgba({bsc},{bsc},{bsc},0.23);");
-                active_shadow = format!("box-shadow: 0 2px 4px rgba({bsc},{bsc},{bsc},0.12), 0 1px 3px rgba({bsc},{bsc},{bsc},0.24);");
-                background_color = format!("background-color: {button_background_color};");
-                background_color_animation_from = format!("background-color: {};", background_color_animation.fade(0.1));
-                background_color_animation_to = format!("background-color: {};", background_color_animation.fade(0.3));
-            } else {
-                background_color = format!("background-color: rgba(29, 27, 32, 0.12);");
-            }
-        },
-        GButtonStyle::Filled => {
+        GButtonStyle::Elevated | GButtonStyle::Filled => {
             if !disabled {
-                let background_color_animation: Color;
-                if button_background_color.lightness() > 0.5 {
-                    button_background_color_on_hover = format!("background-color: {};", button_background_color.shade(0.9));                    
-                    background_color_animation = Color::new(0, 0, 0, 1.0);
+                let container = button_background_color;
+                if *style == GButtonStyle::Elevated {
+                    hover_shadow = format!("box-shadow: {};", GElevation::Level2.box_shadow_var());
+                    active_shadow = format!("box-shadow: {};", GElevation::Level1.box_shadow_var());
                 } else {
-                    button_background_color_on_hover = format!("background-color: {};", button_background_color.tint(0.9));                    
-                    background_color_animation = Color::new(255, 255, 255, 1.0);
+                    hover_shadow = format!("box-shadow: {};", GElevation::Level1.box_shadow_var());
+                    active_shadow = format!("box-shadow: {};", GElevation::Level0.box_shadow_var());
                 }
-                hover_shadow = format!("box-shadow: 0 2px 4px rgba({bsc},{bsc},{bsc},0.12), 0 1px 3px rgba({bsc},{bsc},{bsc},0.24);");
-                active_shadow = format!("box-shadow: 0 0 0 rgba({bsc},{bsc},{bsc},0.0);");
-                background_color = format!("background-color: {button_background_color};");
-                background_color_animation_from = format!("background-color: {};", background_color_animation.fade(0.1));
-                background_color_animation_to = format!("background-color: {};", background_color_animation.fade(0.3));
-            } else {
-                background_color = format!("background-color: rgba(29, 27, 32, 0.12);");
-            }
-        },
-        GButtonStyle::Outlined => {
-            background_color = String::from("background-color: unset;");
-            if !disabled {
-                button_background_color_on_hover = format!("background-color: {};", button_background_color.fade(0.1));
-                background_color_animation_from = format!("background-color: {};", button_background_color.fade(0.1));
-                background_color_animation_to = format!("background-color: {};", button_background_color.fade(0.3));
-                outlined_border_color_set = if let Some(color) = outlined_border_color {
-                    format!("border: 0.0714em solid {};", color)
-                } else {
-                    format!("border: 0.0714em solid #79747E;")
-                };
+                background_color = format!("background-color: {container};");
+                background_color_on_hover = format!("background-color: {};", state_layer.over(&container, GState::Hover));
+                background_color_on_focus = format!("background-color: {};", state_layer.over(&container, GState::Focus));
+                ripple_color = format!("background-color: {};", state_layer.overlay(GState::Pressed));
             } else {
-                outlined_border_color_set = String::from("border: 0.0714em solid rgba(29, 27, 32, 0.12);");
+                background_color = format!("background-color: {disabled_container_color};");
             }
         },
-        GButtonStyle::Text => {
+        GButtonStyle::Outlined | GButtonStyle::Text => {
             background_color = String::from("background-color: unset;");
             if !disabled {
-                button_background_color_on_hover = format!("background-color: {};", button_background_color.fade(0.1));                
-                background_color_animation_from = format!("background-color: {};", button_background_color.fade(0.1));
-                background_color_animation_to = format!("background-color: {};", button_background_color.fade(0.3));
+                background_color_on_hover = format!("background-color: {};", state_layer.overlay(GState::Hover));
+                background_color_on_focus = format!("background-color: {};", state_layer.overlay(GState::Focus));
+                ripple_color = format!("background-color: {};", state_layer.overlay(GState::Pressed));
+                if *style == GButtonStyle::Outlined {
+                    outlined_border_color_set = format!("border: 0.0714em solid {};", outlined_border_color);
+                }
+            } else if *style == GButtonStyle::Outlined {
+                outlined_border_color_set = format!("border: 0.0714em solid {disabled_container_color};");
             }
         },
     }
     if disabled {
-        text_color = AttrValue::from("rgba(29, 27, 32, 0.38)");
+        text_color = on_surface.with_opacity(0.38);
     }
+    let shape_css = shape.css();
+    let forced_text_color = if disabled { "GrayText" } else { "ButtonText" };
+    let shadow_transition = format!("box-shadow {} {}", GDuration::Medium2.var(), GEasing::Standard.var());
+    let ripple_animation = format!("ripple{id} {} {} forwards", GDuration::Long4.var(), GEasing::Standard.var());
     let mut style_str = format!(
         r#"
             div#{g_init} {{
@@ -150,25 +133,35 @@ pub(super) fn input_style(
             button#{id} {{
                 height: {height};
                 {width}
-                padding: 0.7em {padding_right} 0.7em {padding_left};
+                padding-block: {padding_block};
+                padding-inline: {padding_start} {padding_end};
                 border: none;
                 line-height: 1.42em;
                 font-size: inherit;
-                font-weight: 500;
-                border-radius: {border_radius};
+                font-family: {font_family};
+                font-weight: {font_weight};
+                letter-spacing: {letter_spacing};
+                {shape_css}
                 {background_color}
                 outline: none;
                 cursor: pointer;
                 color: inherit;
                 position: relative;
                 overflow: hidden;
-                transition: box-shadow ease-out 0.3s;
+                transition: {shadow_transition};
                 {outlined_border_color_set}
             }}
 
             button#{id}:hover, button#{id}:focus {{
                 {hover_shadow}
-                {button_background_color_on_hover}
+            }}
+
+            button#{id}:hover {{
+                {background_color_on_hover}
+            }}
+
+            button#{id}:focus {{
+                {background_color_on_focus}
             }}
 
             button#{id}, button#{id}:active {{
@@ -183,18 +176,47 @@ pub(super) fn input_style(
                 aspect-ratio: 1;
                 margin-top: -100%;
                 margin-left: -100%;
-                animation: ripple{id} ease-in-out 0.6s forwards;
+                animation: {ripple_animation};
+                {ripple_color}
             }}
 
             @keyframes ripple{id} {{
                 from {{
                     transform: scale(0.2);
-                    {background_color_animation_from}
                 }}
             
                 to {{
                     transform: scale(2);
-                    {background_color_animation_to}
+                }}
+            }}
+
+            {REDUCED_MOTION_MEDIA} {{
+                button#{id} {{
+                    transition: none;
+                }}
+            }}
+
+            {FORCED_COLORS_MEDIA} {{
+                div#{g_init} {{
+                    color: {forced_text_color};
+                }}
+
+                button#{id} {{
+                    border: 0.0714em solid {forced_text_color};
+                }}
+
+                button#{id}:hover, button#{id}:active {{
+                    border-color: Highlight;
+                }}
+
+                button#{id}:focus {{
+                    border-color: Highlight;
+                    outline: 0.1428em solid Highlight;
+                    outline-offset: 0.1428em;
+                }}
+
+                button#{id} > span#g_init_span{id} {{
+                    display: none;
                 }}
             }}
         "#
@@ -209,7 +231,7 @@ pub(super) fn input_style(
                 position: absolute;
                 top: 50%;
                 transform: translateY(-50%);
-                right: {icon_align};
+                inset-inline-end: {icon_align};
                 pointer-events: none;
             }}
     
@@ -225,7 +247,7 @@ pub(super) fn input_style(
                 position: absolute;  
                 top: 50%;
                 transform: translateY(-50%);
-                left: {icon_align};
+                inset-inline-start: {icon_align};
                 pointer-events: none;
             }}
     
@@ -1,7 +1,7 @@
 //! # GButton
 //! is similar to google material common buttons (not identical)
 //! 
-//! The key size attribute of button is `font_size`. It bonds a lot of other sizes and has the default value 14px. 
+//! The key size attribute of button is `font_size`. It bonds a lot of other sizes and has the default value 14px (`label-large` role of the type scale, see `GTypescale`). 
 //! According to this 1px here = 0.0714em
 //! 
 //! GButton has a lot of attributes (and you can make something similar to FAB button via them), but only `id` are required. If you use icon in button, `icon_style` attribute is also required. 
@@ -16,31 +16,45 @@
 //!- style: `GButtonStyle`,
 //![default GButtonStyle::Filled]
 //!- outlined_border_color: `Option<AttrValue>`,
-//![default "#79747E"]
-//!- font_size: `AttrValue`, 
-//![default "14px"]
+//![default None] Uses `outline` color of `GTheme`
+//!- font_size: `Option<AttrValue>`, 
+//![default None] Uses `--md-sys-typescale-label-large-size` token of `GThemeProvider` (14px)
 //!- onclick: `Option<Callback<PointerEvent>>`,
 //![default None] Use PointerEvent instead of MouseEvent
 //!- class: `AttrValue`,
 //![default ""]
-//!- height: `AttrValue`,
-//![default "2.85em"]
+//!- height: `Option<AttrValue>`,
+//![default None] 2.85em (40px with 14px font size), 4px lower for every step of `density`
+//!- density: `Option<GDensity>`,
+//![default None] Uses `density` of `GTheme` (see `GDensity`), paddings and the icon offset shrink together with the height
 //!- width: `Option<AttrValue>`,
 //![default None]
 //!- parent: `DependsOn`,
 //![default None] This attribute required only with GTextInput
-//!- background_color: `AttrValue`,
-//![default "#6750A4"]
-//!- label_color: `AttrValue`, 
-//![default "#ffffff"]
-//!- border_radius: `AttrValue`,
-//![default "20px"] It is similar to container_shape in google material buttons
-//!- has_icon: `bool`,
-//![default false]
+//!- background_color: `Option<AttrValue>`,
+//![default None] Uses `primary` color of `GTheme` (`surface` for Elevated buttons)
+//!- label_color: `Option<AttrValue>`, 
+//![default None] Uses `on_primary` color of `GTheme` for Filled buttons and `primary` for Elevated, Outlined and Text buttons. 
+//! If `background_color` of Filled or Elevated button is set, black or white label color is chosen automatically to keep the text readable. 
+//! In debug builds a warning is logged to the browser console if contrast of `label_color` and container color is below 4.5:1
+//!- shape: `GShape`,
+//![default GShapeCorner::Full] Shape token of the theme shape scale, e.g. `GShapeCorner::Medium` or `GShape::cut(GShapeCorner::Small)` (see `GShape`)
+//!- has_icon: `Option<AttrValue>`,
+//![default None] `MaterialSymbol` or icon name as a string
 //!- trailing_icon: `bool`,
 //![default false]
-//!- dark_theame: `bool`,
-//![default false] Experimental! Now it changes shadows from black to white if true.
+//!- icon_fallback: `Option<AttrValue>`,
+//![default None] glyph shown instead of the icon if the icon font is not loaded in time (see `fonts` module)
+//!- fill_on_hover: `bool`,
+//![default false] the icon is filled while the button is hovered
+//!- hover_grade: `Option<IconGrade>`,
+//![default None] grade of the icon while the button is hovered
+//!- press_wght: `Option<IconWeight>`,
+//![default None] weight of the icon while the button is pressed
+//!- selected: `bool`,
+//![default false] the icon is filled, or `selected_icon` is shown
+//!- selected_icon: `Option<AttrValue>`,
+//![default None] icon cross-faded with `has_icon` when `selected` changes, e.g. `MaterialSymbol::Close` for a menu button
 //!- disabled: `bool`,
 //![default false]
 //! 
@@ -56,6 +70,7 @@
 //! 
 //! Also you can add icon with `has_icon` attribute. If so, you also need to set `icon_style` attribute together with stylesheet inside `<head></head>`(see GIcon docs). If you need trailing icon use `trailing_icon` with `true` together with `has_icon` attributes in GButton.
 //! To adjust icon parameters use `fill`, `wght`, `grade`, `opsz` attributes as well as with GIcon.
+//! `has_icon` can also be a name registered in `GIconRegistry` (see `icons::registry`).
 //! 
 //! Attention! The way to add icon in this version is different from v.0.0.7. 
 //! ```
@@ -81,7 +96,7 @@ use web_sys::HtmlElement;
 use yew::platform::spawn_local;
 use yew::prelude::*;
 use wasm_bindgen::JsCast;
-use crate::{GButtonStyle, GIconStyle, icons::GIcon};
+use crate::{GButtonStyle, GIconStyle, fonts::inject_roboto, icons::{GIcon, IconGrade, IconWeight, OpticalSize}, theme::{contrast_ratio, density_size, on_color, sys_color, prefers_reduced_motion, GDensity, GDuration, GShape, GTheme, GTypescale, MIN_CONTRAST_RATIO}};
 
 mod button_css;
 
@@ -109,44 +124,56 @@ pub struct GButtonProps {
     pub style: GButtonStyle,
     #[prop_or_default]
     pub outlined_border_color: Option<AttrValue>,
-    #[prop_or_else(|| AttrValue::from("14px"))]
-    pub font_size: AttrValue, 
+    #[prop_or_default]
+    pub font_size: Option<AttrValue>, 
     #[prop_or_default]
     pub onclick: Option<Callback<PointerEvent>>,
     #[prop_or_default]
     pub class: AttrValue,
-    #[prop_or_else(|| AttrValue::from("2.85em"))]
-    pub height: AttrValue,
+    #[prop_or_default]
+    pub height: Option<AttrValue>,
+    #[prop_or_default]
+    pub density: Option<GDensity>,
     #[prop_or_default]
     pub width: Option<AttrValue>,
     #[prop_or_default]
     pub children: Html,
     #[prop_or_default]
     pub parent: DependsOn,
-    #[prop_or_else(|| AttrValue::from("#6750A4"))]
-    pub background_color: AttrValue,
-    #[prop_or_else(|| AttrValue::from("#FFFFFF"))]
-    pub label_color: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("20px"))]
-    pub border_radius: AttrValue,
+    #[prop_or_default]
+    pub background_color: Option<AttrValue>,
+    #[prop_or_default]
+    pub label_color: Option<AttrValue>, 
+    #[prop_or_default]
+    pub shape: GShape,
     #[prop_or_default]
     pub has_icon: Option<AttrValue>,
     #[prop_or_default]
     pub trailing_icon: bool,
     #[prop_or_default]
+    pub icon_fallback: Option<AttrValue>,
+    #[prop_or_default]
+    pub fill_on_hover: bool,
+    #[prop_or_default]
+    pub hover_grade: Option<IconGrade>,
+    #[prop_or_default]
+    pub press_wght: Option<IconWeight>,
+    #[prop_or_default]
+    pub selected: bool,
+    #[prop_or_default]
+    pub selected_icon: Option<AttrValue>,
+    #[prop_or_default]
     pub icon_style: Option<GIconStyle>,
     #[prop_or_default]
     pub autofocus: bool,
     #[prop_or_else(|| false )]
     pub fill: bool,
-    #[prop_or_else(|| AttrValue::from("300"))]
-    pub wght: AttrValue,
-    #[prop_or_else(|| AttrValue::from("100"))]
-    pub grade: AttrValue,
-    #[prop_or_else(|| AttrValue::from("24"))]
-    pub opsz: AttrValue,
     #[prop_or_default]
-    pub dark_theame: bool,
+    pub wght: IconWeight,
+    #[prop_or_default]
+    pub grade: IconGrade,
+    #[prop_or_default]
+    pub opsz: OpticalSize,
     #[prop_or_default]
     pub disabled: bool,
 }
@@ -198,7 +225,7 @@ impl Component for GButton {
                     let y = event.client_y() - button_rect.top().round() as i32;
                     format!("{y}px")
                 };
-                if !ctx.props().disabled {
+                if !ctx.props().disabled && !reduce_motion(ctx) {
                     ripple_effect(onmouse, &x, &y, button, &ctx.props().id);
                 }
             },
@@ -214,7 +241,7 @@ impl Component for GButton {
                         let y = button.offset_height() / 2;
                         format!("{y}px")
                     };
-                    if !ctx.props().disabled {
+                    if !ctx.props().disabled && !reduce_motion(ctx) {
                         ripple_effect(onmouse, &x, &y, button, &ctx.props().id);
                     }
                     if let Some(onclick) = ctx.props().onclick.as_ref() {
@@ -242,22 +269,24 @@ impl Component for GButton {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let g_init = AttrValue::from(format!("g_init_{}", ctx.props().id));
         let has_icon = if ctx.props().has_icon.is_some() { true } else { false };
+        let (background_color, label_color) = container_and_label_colors(ctx.props(), sys_color);
+        let outlined_border_color = ctx.props().outlined_border_color.clone().unwrap_or_else(|| sys_color("outline"));
         let stylesheet = input_style(
             &ctx.props().style,
             &ctx.props().id,
             self.only_icon,
             &g_init,
-            ctx.props().font_size.clone(),
-            ctx.props().height.clone(),
+            ctx.props().font_size.clone().unwrap_or_else(|| GTypescale::LabelLarge.var("size")),
+            ctx.props().height.clone().unwrap_or_else(|| density_size("2.85em", "0.2857em", ctx.props().density)),
+            ctx.props().density,
             &ctx.props().width,
-            &ctx.props().background_color,
-            ctx.props().label_color.clone(),
-            &ctx.props().outlined_border_color,
-            ctx.props().border_radius.clone(),
+            &background_color,
+            label_color,
+            &outlined_border_color,
+            ctx.props().shape,
             ctx.props().disabled,
             has_icon,
             ctx.props().trailing_icon,
-            ctx.props().dark_theame,
             &ctx.props().parent,
         );
 
@@ -287,11 +316,17 @@ impl Component for GButton {
                                 icon={ctx.props().has_icon.clone().unwrap()}
                                 icon_style={ctx.props().icon_style.clone().unwrap()}
                                 fill={ctx.props().fill}
-                                wght={&ctx.props().wght}
-                                grade={&ctx.props().grade}
-                                opsz={&ctx.props().opsz}
+                                wght={ctx.props().wght}
+                                grade={ctx.props().grade}
+                                opsz={ctx.props().opsz}
                                 leading_icon={self.leading_icon}
                                 trailing_icon={ctx.props().trailing_icon}
+                                fallback={ctx.props().icon_fallback.clone()}
+                                fill_on_hover={ctx.props().fill_on_hover}
+                                hover_grade={ctx.props().hover_grade}
+                                press_wght={ctx.props().press_wght}
+                                selected={ctx.props().selected}
+                                selected_icon={ctx.props().selected_icon.clone()}
                             />
                         }
                         {ctx.props().children.clone()}
@@ -303,6 +338,27 @@ impl Component for GButton {
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         if first_render {
+            // inside GThemeProvider Roboto is injected by the provider if the theme typography uses it
+            if ctx.link().context::<GTheme>(Callback::noop()).is_none() {
+                inject_roboto();
+            }
+            if cfg!(debug_assertions) && ctx.props().label_color.is_some() && !ctx.props().disabled {
+                let theme = ctx.link().context::<GTheme>(Callback::noop()).map(|(theme, _)| theme).unwrap_or_default();
+                let theme_color = |role: &str| theme.colors().role(role).cloned().unwrap_or_default();
+                let (container_color, label_color) = container_and_label_colors(ctx.props(), theme_color);
+                let container_color = match ctx.props().style {
+                    GButtonStyle::Filled | GButtonStyle::Elevated => container_color,
+                    GButtonStyle::Outlined | GButtonStyle::Text => theme_color("surface"),
+                };
+                if let Some(ratio) = contrast_ratio(&label_color, &container_color) {
+                    if ratio < MIN_CONTRAST_RATIO {
+                        crate::warn(&format!(
+                            "GButton#{}: contrast ratio of label color {label_color} and container color {container_color} is {ratio:.2}:1, it is below {MIN_CONTRAST_RATIO}:1",
+                            ctx.props().id
+                        ));
+                    }
+                }
+            }
             match ctx.props().parent {
                 DependsOn::GTextInput => {
                     let button = self.button_node.cast::<HtmlElement>().unwrap();
@@ -310,9 +366,9 @@ impl Component for GButton {
                     let button_height = button.query_selector("button").unwrap().unwrap().client_height() as f64;
                     let icon_margin_top_and_side = (input_height - button_height) / 2.0 + 1.0;
                     let button_align = if self.leading_icon {
-                        "left"
+                        "inset-inline-start"
                     } else {
-                        "right"
+                        "inset-inline-end"
                     };
                     let css = format!(r#"
                         display: block;
@@ -328,6 +384,24 @@ impl Component for GButton {
     }
 }
 
+fn container_and_label_colors(props: &GButtonProps, color: impl Fn(&str) -> AttrValue) -> (AttrValue, AttrValue) {
+    let background_color = props.background_color.clone().unwrap_or_else(|| match props.style {
+        GButtonStyle::Elevated => color("surface"),
+        _ => color("primary"),
+    });
+    let label_color = props.label_color.clone().unwrap_or_else(|| match (&props.style, &props.background_color) {
+        (GButtonStyle::Filled | GButtonStyle::Elevated, Some(background_color)) => on_color(background_color),
+        (GButtonStyle::Filled, None) => color("on-primary"),
+        _ => color("primary"),
+    });
+    (background_color, label_color)
+}
+
+fn reduce_motion(ctx: &Context<GButton>) -> bool {
+    let theme_reduce_motion = ctx.link().context::<GTheme>(Callback::noop()).map(|(theme, _)| theme.reduce_motion).unwrap_or(false);
+    theme_reduce_motion || prefers_reduced_motion()
+}
+
 fn ripple_effect(onmouse: bool, x: &str, y: &str, button: HtmlElement, id: &AttrValue) {
     let span = button
         .owner_document()
@@ -344,7 +418,7 @@ fn ripple_effect(onmouse: bool, x: &str, y: &str, button: HtmlElement, id: &Attr
     button.append_child(&span).unwrap();
     if !onmouse {
         spawn_local(async move {
-            TimeoutFuture::new(300).await;
+            TimeoutFuture::new(GDuration::Medium2.millis()).await;
             span.remove()
         })
     }
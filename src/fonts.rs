@@ -0,0 +1,241 @@
+//! # Fonts
+//! are injected into `<head></head>` automatically, so `<link>` tags in `index.html` are not required.
+//!
+//! `GIcon` adds the stylesheet of Material Symbols family of its `GIconStyle` when it is rendered for the first time,
+//! so only the families used by the app are loaded. `GThemeProvider` adds Roboto if the theme typography uses it,
+//! `GButton`, `GTextInput` and `GText` outside a provider add it when they are rendered for the first time.
+//! A stylesheet is not added twice and is not added if `<head></head>` already has a link to the same family.
+//!
+//! Call `init_fonts` before rendering the app to change the defaults:
+//! - base_url: `AttrValue`,
+//! [default "https://fonts.googleapis.com"] e.g. a self-hosted mirror for offline deployments, it must serve the same `/css2?family=...` paths
+//! - inject: `bool`,
+//! [default true] `false` turns the injection off, then add `<link>` tags yourself (see `GIcon` docs)
+//! - roboto: `bool`,
+//! [default true] `false` turns off Roboto only
+//! - icon_timeout: `u32`,
+//! [default 3000] milliseconds to wait for the icon font, then GIcon shows its `fallback` or the icon name
+//!
+//! Until the icon font is loaded (it is watched with `document.fonts`) GIcon renders an invisible placeholder of the icon size,
+//! so words like "search" are not shown inside buttons and text fields. If the font is loaded after the timeout, icons are shown then.
+//!
+//! ## Examples
+//! ```no_run
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     html! { <GIcon icon={MaterialSymbol::Search} icon_style={GIconStyle::Outlined} /> }
+//! }
+//!
+//! fn main() {
+//!     init_fonts(GFonts {
+//!         base_url: AttrValue::from("https://fonts.example.com"),
+//!         ..GFonts::default()
+//!     });
+//!     yew::Renderer::<App>::new().render();
+//! }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use gloo_events::EventListener;
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{js_sys, FontFaceSet};
+use yew::platform::spawn_local;
+use yew::{AttrValue, Callback};
+
+use crate::GIconStyle;
+
+const ICON_AXES: &str = "opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200";
+const ROBOTO_WEIGHTS: &str = "wght@400;500";
+const ICON_FONT_POLL: u32 = 100;
+
+/// State of an icon font family and the callbacks of mounted GIcons waiting for the next state
+struct IconFontWatch {
+    state: IconFontState,
+    waiters: HashMap<u32, Callback<IconFontState>>,
+}
+
+thread_local! {
+    static FONTS: RefCell<GFonts> = RefCell::new(GFonts::default());
+    static ICON_FONTS: RefCell<HashMap<&'static str, IconFontWatch>> = RefCell::new(HashMap::new());
+    static NEXT_WAITER: Cell<u32> = const { Cell::new(0) };
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GFonts {
+    pub base_url: AttrValue,
+    pub inject: bool,
+    pub roboto: bool,
+    pub icon_timeout: u32,
+}
+
+impl Default for GFonts {
+    fn default() -> Self {
+        Self {
+            base_url: AttrValue::from("https://fonts.googleapis.com"),
+            inject: true,
+            roboto: true,
+            icon_timeout: 3000,
+        }
+    }
+}
+
+pub fn init_fonts(fonts: GFonts) {
+    FONTS.with(|config| *config.borrow_mut() = fonts);
+}
+
+pub(crate) fn icon_font_family(style: &GIconStyle) -> &'static str {
+    match style {
+        GIconStyle::Outlined => "Material Symbols Outlined",
+        GIconStyle::Rounded => "Material Symbols Rounded",
+        GIconStyle::Sharp => "Material Symbols Sharp",
+    }
+}
+
+pub(crate) fn inject_icon_font(style: &GIconStyle) {
+    let fonts = FONTS.with(|config| config.borrow().clone());
+    if fonts.inject {
+        // `block` hides the ligature text until the font is loaded
+        inject_stylesheet(&fonts.base_url, icon_font_family(style), ICON_AXES, "block");
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum IconFontState {
+    Loading,
+    TimedOut,
+    Ready,
+}
+
+pub(crate) fn icon_font_state(style: &GIconStyle) -> IconFontState {
+    ICON_FONTS.with(|icon_fonts| {
+        icon_fonts
+            .borrow()
+            .get(icon_font_family(style))
+            .map_or(IconFontState::Loading, |watch| watch.state)
+    })
+}
+
+/// Current state of the icon font and the id of `callback` if the font is not ready.
+/// `callback` gets the next state only, call again to wait further and `unwatch_icon_font` when the icon is unmounted.
+/// The first call for a family starts watching `document.fonts`
+pub(crate) fn watch_icon_font(style: &GIconStyle, callback: Callback<IconFontState>) -> (IconFontState, Option<u32>) {
+    let family = icon_font_family(style);
+    let (state, id, started) = ICON_FONTS.with(|icon_fonts| {
+        let mut icon_fonts = icon_fonts.borrow_mut();
+        let started = icon_fonts.contains_key(family);
+        let watch = icon_fonts.entry(family).or_insert_with(|| IconFontWatch {
+            state: IconFontState::Loading,
+            waiters: HashMap::new(),
+        });
+        let id = (watch.state != IconFontState::Ready).then(|| {
+            let id = NEXT_WAITER.with(|next| next.replace(next.get().wrapping_add(1)));
+            watch.waiters.insert(id, callback);
+            id
+        });
+        (watch.state, id, started)
+    });
+    if !started {
+        spawn_local(wait_for_icon_font(family));
+    }
+    (state, id)
+}
+
+pub(crate) fn unwatch_icon_font(style: &GIconStyle, id: u32) {
+    ICON_FONTS.with(|icon_fonts| {
+        if let Some(watch) = icon_fonts.borrow_mut().get_mut(icon_font_family(style)) {
+            watch.waiters.remove(&id);
+        }
+    });
+}
+
+fn set_icon_font_state(family: &'static str, state: IconFontState) {
+    let waiters = ICON_FONTS.with(|icon_fonts| {
+        let mut icon_fonts = icon_fonts.borrow_mut();
+        let Some(watch) = icon_fonts.get_mut(family) else {
+            return HashMap::new();
+        };
+        if watch.state == state || watch.state == IconFontState::Ready {
+            return HashMap::new();
+        }
+        watch.state = state;
+        std::mem::take(&mut watch.waiters)
+    });
+    for waiter in waiters.into_values() {
+        waiter.emit(state);
+    }
+}
+
+async fn wait_for_icon_font(family: &'static str) {
+    let Some(fonts) = web_sys::window().and_then(|window| window.document()).map(|document| document.fonts()) else {
+        set_icon_font_state(family, IconFontState::Ready);
+        return;
+    };
+    let timeout = FONTS.with(|config| config.borrow().icon_timeout);
+    spawn_local(async move {
+        TimeoutFuture::new(timeout).await;
+        set_icon_font_state(family, IconFontState::TimedOut);
+    });
+
+    let font = format!("24px \"{family}\"");
+    let deadline = js_sys::Date::now() + timeout as f64;
+    while js_sys::Date::now() < deadline {
+        if icon_font_loaded(&fonts, &font).await {
+            set_icon_font_state(family, IconFontState::Ready);
+            return;
+        }
+        TimeoutFuture::new(ICON_FONT_POLL).await;
+    }
+
+    // the font can still come after the timeout on a slow network
+    let target = fonts.clone();
+    EventListener::new(&target, "loadingdone", move |_| {
+        let fonts = fonts.clone();
+        let font = font.clone();
+        spawn_local(async move {
+            if icon_font_loaded(&fonts, &font).await {
+                set_icon_font_state(family, IconFontState::Ready);
+            }
+        });
+    })
+    .forget();
+}
+
+async fn icon_font_loaded(fonts: &FontFaceSet, font: &str) -> bool {
+    // `load` resolves with no faces while the stylesheet with the family is not loaded yet
+    JsFuture::from(fonts.load(font))
+        .await
+        .is_ok_and(|faces| js_sys::Array::from(&faces).length() > 0)
+}
+
+pub(crate) fn inject_roboto() {
+    let fonts = FONTS.with(|config| config.borrow().clone());
+    if fonts.inject && fonts.roboto {
+        inject_stylesheet(&fonts.base_url, "Roboto", ROBOTO_WEIGHTS, "swap");
+    }
+}
+
+fn inject_stylesheet(base_url: &str, family: &str, axes: &str, display: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Some(head) = document.head() else {
+        return;
+    };
+    let family = family.replace(' ', "+");
+    let selector = format!("link[href*='family={family}:'], link[href*='family={family}&'], link[href$='family={family}']");
+    if let Ok(Some(_)) = head.query_selector(&selector) {
+        return;
+    }
+    let href = format!("{}/css2?family={family}:{axes}&display={display}", base_url.trim_end_matches('/'));
+    if let Ok(link) = document.create_element("link") {
+        let _ = link.set_attribute("rel", "stylesheet");
+        let _ = link.set_attribute("href", &href);
+        let _ = head.append_child(&link);
+    }
+}
@@ -0,0 +1,318 @@
+//! # Icon axes
+//! `IconWeight` (`wght`, 100..=700), `IconGrade` (`GRAD`, -50..=200) and `OpticalSize` (`opsz`, 20..=48)
+//! are the variable font axes of Material Symbols `https://fonts.google.com/knowledge/glossary/axis_in_variable_fonts`.
+//!
+//! `OpticalSize::from_size` derives the optical size from the icon size, `GIcon` does it for `OpticalSize::AUTO` (default, also `opsz="auto"`).
+//!
+//! `new` returns an error for values out of range. `From` for numbers and strings never fails:
+//! values out of range are clamped and strings which can not be parsed fall back to the default,
+//! in both cases a warning is logged to the browser console.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let wght = IconWeight::new(400).expect("Wrong icon weight");
+//! let grade = IconGrade::from(-25);
+//! let opsz = OpticalSize::from("48");
+//!
+//! <GIcon icon={MaterialSymbol::Star} icon_style={GIconStyle::Rounded} {wght} {grade} {opsz} />
+//! <GIcon icon={MaterialSymbol::Star} icon_style={GIconStyle::Rounded} wght={200} grade={0} opsz="20" />
+//! <GIcon icon={MaterialSymbol::Star} icon_style={GIconStyle::Rounded} wght={config.icon_weight.clone()} />  // String or AttrValue
+//! ```
+
+use std::fmt;
+
+use yew::html::IntoPropValue;
+use yew::AttrValue;
+
+fn clamp_axis(axis: &str, value: i32, min: i32, max: i32) -> i32 {
+    if value < min || value > max {
+        crate::warn(&format!("Icon {axis} {value} is out of range {min}..={max}, it is clamped"));
+    }
+    value.clamp(min, max)
+}
+
+fn parse_axis(axis: &str, value: &str, default: i32) -> i32 {
+    value.trim().parse::<i32>().unwrap_or_else(|_| {
+        crate::warn(&format!("Icon {axis} \"{value}\" is not a number, {default} is used"));
+        default
+    })
+}
+
+fn check_axis(axis: &str, value: i32, min: i32, max: i32) -> Result<i32, String> {
+    if (min..=max).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!("Icon {axis} {value} is out of range {min}..={max}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct IconWeight(u16);
+
+impl IconWeight {
+    pub const MIN: u16 = 100;
+    pub const MAX: u16 = 700;
+
+    pub fn new(wght: u16) -> Result<Self, String> {
+        check_axis("wght", wght as i32, Self::MIN as i32, Self::MAX as i32).map(|wght| Self(wght as u16))
+    }
+
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for IconWeight {
+    fn default() -> Self {
+        Self(300)
+    }
+}
+
+impl From<i32> for IconWeight {
+    fn from(wght: i32) -> Self {
+        Self(clamp_axis("wght", wght, Self::MIN as i32, Self::MAX as i32) as u16)
+    }
+}
+
+impl From<&str> for IconWeight {
+    fn from(wght: &str) -> Self {
+        Self::from(parse_axis("wght", wght, Self::default().0 as i32))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct IconGrade(i16);
+
+impl IconGrade {
+    pub const MIN: i16 = -50;
+    pub const MAX: i16 = 200;
+
+    pub fn new(grade: i16) -> Result<Self, String> {
+        check_axis("grade", grade as i32, Self::MIN as i32, Self::MAX as i32).map(|grade| Self(grade as i16))
+    }
+
+    pub fn value(&self) -> i16 {
+        self.0
+    }
+}
+
+impl Default for IconGrade {
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+impl From<i32> for IconGrade {
+    fn from(grade: i32) -> Self {
+        Self(clamp_axis("grade", grade, Self::MIN as i32, Self::MAX as i32) as i16)
+    }
+}
+
+impl From<&str> for IconGrade {
+    fn from(grade: &str) -> Self {
+        Self::from(parse_axis("grade", grade, Self::default().0 as i32))
+    }
+}
+
+/// `OpticalSize::AUTO` (default) derives the optical size from the icon size
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct OpticalSize(Option<u8>);
+
+impl OpticalSize {
+    pub const MIN: u8 = 20;
+    pub const MAX: u8 = 48;
+    pub const AUTO: OpticalSize = OpticalSize(None);
+
+    pub fn new(opsz: u8) -> Result<Self, String> {
+        check_axis("opsz", opsz as i32, Self::MIN as i32, Self::MAX as i32).map(|opsz| Self(Some(opsz as u8)))
+    }
+
+    /// `None` for `OpticalSize::AUTO`
+    pub fn value(&self) -> Option<u8> {
+        self.0
+    }
+
+    pub fn is_auto(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Optical size for the css font size of the icon, e.g. `48px`, `1.5em` (16px is taken as 1em) or `18pt`, clamped to 20..=48.
+    /// Returns `None` if the size can not be resolved without the page, e.g. `100%` or `var(--icon-size)`
+    pub fn from_size(size: &str) -> Option<Self> {
+        let size = size.trim();
+        if !size.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            return None;
+        }
+        let (number, unit) = crate::parse_number_and_unit(AttrValue::from(size.to_string()));
+        let px = match unit.as_str() {
+            "px" | "" => number,
+            "em" | "rem" => number * 16.0,
+            "pt" => number * 4.0 / 3.0,
+            _ => return None,
+        };
+        Some(Self(Some(px.round().clamp(Self::MIN as f64, Self::MAX as f64) as u8)))
+    }
+}
+
+impl From<i32> for OpticalSize {
+    fn from(opsz: i32) -> Self {
+        Self(Some(clamp_axis("opsz", opsz, Self::MIN as i32, Self::MAX as i32) as u8))
+    }
+}
+
+impl From<&str> for OpticalSize {
+    fn from(opsz: &str) -> Self {
+        match opsz.trim() {
+            "auto" => Self::AUTO,
+            value => value.parse::<i32>().map(Self::from).unwrap_or_else(|_| {
+                crate::warn(&format!("Icon opsz \"{opsz}\" is not a number, auto is used"));
+                Self::AUTO
+            }),
+        }
+    }
+}
+
+impl fmt::Display for IconWeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for IconGrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for OpticalSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(opsz) => opsz.fmt(f),
+            None => f.write_str("auto"),
+        }
+    }
+}
+
+impl IntoPropValue<IconWeight> for i32 {
+    fn into_prop_value(self) -> IconWeight {
+        IconWeight::from(self)
+    }
+}
+
+impl IntoPropValue<IconWeight> for &'static str {
+    fn into_prop_value(self) -> IconWeight {
+        IconWeight::from(self)
+    }
+}
+
+impl IntoPropValue<IconWeight> for String {
+    fn into_prop_value(self) -> IconWeight {
+        IconWeight::from(self.as_str())
+    }
+}
+
+impl IntoPropValue<IconWeight> for AttrValue {
+    fn into_prop_value(self) -> IconWeight {
+        IconWeight::from(self.as_str())
+    }
+}
+
+impl IntoPropValue<IconGrade> for i32 {
+    fn into_prop_value(self) -> IconGrade {
+        IconGrade::from(self)
+    }
+}
+
+impl IntoPropValue<IconGrade> for &'static str {
+    fn into_prop_value(self) -> IconGrade {
+        IconGrade::from(self)
+    }
+}
+
+impl IntoPropValue<IconGrade> for String {
+    fn into_prop_value(self) -> IconGrade {
+        IconGrade::from(self.as_str())
+    }
+}
+
+impl IntoPropValue<IconGrade> for AttrValue {
+    fn into_prop_value(self) -> IconGrade {
+        IconGrade::from(self.as_str())
+    }
+}
+
+impl IntoPropValue<OpticalSize> for i32 {
+    fn into_prop_value(self) -> OpticalSize {
+        OpticalSize::from(self)
+    }
+}
+
+impl IntoPropValue<OpticalSize> for &'static str {
+    fn into_prop_value(self) -> OpticalSize {
+        OpticalSize::from(self)
+    }
+}
+
+impl IntoPropValue<OpticalSize> for String {
+    fn into_prop_value(self) -> OpticalSize {
+        OpticalSize::from(self.as_str())
+    }
+}
+
+impl IntoPropValue<OpticalSize> for AttrValue {
+    fn into_prop_value(self) -> OpticalSize {
+        OpticalSize::from(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_and_parse() {
+        assert_eq!(clamp_axis("wght", 400, 100, 700), 400);
+        assert_eq!(clamp_axis("wght", 50, 100, 700), 100);
+        assert_eq!(clamp_axis("wght", 900, 100, 700), 700);
+        assert_eq!(parse_axis("wght", " 200 ", 300), 200);
+        assert_eq!(parse_axis("wght", "bold", 300), 300);
+    }
+
+    #[test]
+    fn from_numbers_and_strings() {
+        assert_eq!(IconWeight::from(900).value(), IconWeight::MAX);
+        assert_eq!(IconWeight::from("bold"), IconWeight::default());
+        assert_eq!(IconGrade::from(-100).value(), IconGrade::MIN);
+        assert_eq!(IconGrade::from("25").value(), 25);
+        assert_eq!(OpticalSize::from("auto"), OpticalSize::AUTO);
+        assert_eq!(OpticalSize::from("large"), OpticalSize::AUTO);
+        assert_eq!(OpticalSize::from("60").value(), Some(OpticalSize::MAX));
+    }
+
+    #[test]
+    fn new_checks_range() {
+        assert!(IconWeight::new(100).is_ok());
+        assert!(IconWeight::new(800).is_err());
+        assert!(IconGrade::new(-50).is_ok());
+        assert!(IconGrade::new(201).is_err());
+        assert_eq!(OpticalSize::new(48).unwrap().value(), Some(48));
+        assert!(OpticalSize::new(19).is_err());
+    }
+
+    #[test]
+    fn optical_size_from_size() {
+        let opsz = |size: &str| OpticalSize::from_size(size).and_then(|opsz| opsz.value());
+        assert_eq!(opsz("24px"), Some(24));
+        assert_eq!(opsz("40"), Some(40));
+        assert_eq!(opsz("1.5em"), Some(24));
+        assert_eq!(opsz("2rem"), Some(32));
+        assert_eq!(opsz("18pt"), Some(24));
+        assert_eq!(opsz("12px"), Some(20));
+        assert_eq!(opsz("96px"), Some(48));
+        assert_eq!(opsz("var(--icon-size)"), None);
+        assert_eq!(opsz("100%"), None);
+    }
+}
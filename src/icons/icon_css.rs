@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use stylist::Style;
+use yew::prelude::*;
+
+use crate::{theme::{FORCED_COLORS_MEDIA, REDUCED_MOTION_MEDIA}, GIconStyle};
+
+use super::{IconGrade, IconWeight, OpticalSize};
+
+thread_local! {
+    static ICON_STYLES: RefCell<HashMap<IconStyleKey, Style>> = RefCell::new(HashMap::new());
+}
+
+/// Everything the icon css depends on, icons with the same key share one class
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub(super) struct IconStyleKey {
+    pub icon_style: GIconStyle,
+    pub fill: bool,
+    pub wght: IconWeight,
+    pub grade: IconGrade,
+    pub opsz: Option<OpticalSize>,
+    pub fill_on_hover: bool,
+    pub hover_grade: Option<IconGrade>,
+    pub press_wght: Option<IconWeight>,
+    pub color: AttrValue,
+    pub size: AttrValue,
+    pub transition: AttrValue,
+}
+
+pub(super) fn universal_style(icon_style: &GIconStyle) -> &'static str {
+    match icon_style {
+        GIconStyle::Outlined => "material-symbols-outlined",
+        GIconStyle::Rounded => "material-symbols-rounded",
+        GIconStyle::Sharp => "material-symbols-sharp",
+    }
+}
+
+pub(super) fn icon_style(key: IconStyleKey) -> Style {
+    ICON_STYLES.with(|styles| styles.borrow_mut().entry(key).or_insert_with_key(new_icon_style).clone())
+}
+
+fn variation_settings(fill: bool, wght: IconWeight, grade: IconGrade, opsz: Option<OpticalSize>) -> String {
+    let fill = if fill { 1_u8 } else { 0_u8 };
+    let opsz = match opsz {
+        Some(opsz) => format!(",\n                'opsz' {opsz}"),
+        None => String::new(),
+    };
+    format!("'FILL' {fill},\n                'wght' {wght},\n                'GRAD' {grade}{opsz}")
+}
+
+fn new_icon_style(key: &IconStyleKey) -> Style {
+    let universal_slyle = universal_style(&key.icon_style);
+    let IconStyleKey { color, size, transition, .. } = key;
+    let variation = variation_settings(key.fill, key.wght, key.grade, key.opsz);
+
+    let mut style_str = format!(
+        r#"
+            .{universal_slyle} {{
+                display: block;
+                transition: {transition};
+                color: {color};
+                font-size: {size};
+                font-variation-settings:
+                {variation};
+                font-optical-sizing: auto;
+                }}
+
+            .{universal_slyle}.g_icon_loading,
+            .{universal_slyle}.g_icon_fallback {{
+                width: 1em;
+                height: 1em;
+                overflow: hidden;
+                }}
+
+            .{universal_slyle}.g_icon_fallback {{
+                font-family: inherit;
+                line-height: 1;
+                text-align: center;
+                }}
+
+            .{universal_slyle}.g_icon_swap:not(.g_icon_shown) {{
+                position: absolute;
+                inset: 0;
+                opacity: 0;
+                }}
+
+            .{universal_slyle} > svg {{
+                display: block;
+                width: 1em;
+                height: 1em;
+                }}
+
+            {REDUCED_MOTION_MEDIA} {{
+                .{universal_slyle} {{
+                    transition: none;
+                }}
+            }}
+
+            {FORCED_COLORS_MEDIA} {{
+                .{universal_slyle} {{
+                    color: CanvasText;
+                }}
+            }}
+        "#
+    );
+
+    // a standalone icon is hovered itself, an icon of GButton is a sibling of the button
+    let hover_fill = key.fill || key.fill_on_hover;
+    let hover_grade = key.hover_grade.unwrap_or(key.grade);
+    if key.fill_on_hover || key.hover_grade.is_some() {
+        let hover = variation_settings(hover_fill, key.wght, hover_grade, key.opsz);
+        style_str.push_str(&format!(r#"
+            gicon:hover > & .{universal_slyle},
+            button:enabled:hover ~ gicon > & .{universal_slyle} {{
+                font-variation-settings:
+                {hover};
+                }}
+        "#));
+    }
+    if let Some(press_wght) = key.press_wght {
+        let press = variation_settings(hover_fill, press_wght, hover_grade, key.opsz);
+        style_str.push_str(&format!(r#"
+            gicon:active > & .{universal_slyle},
+            button:enabled:active ~ gicon > & .{universal_slyle} {{
+                font-variation-settings:
+                {press};
+                }}
+        "#));
+    }
+
+    Style::new(style_str).expect("Failed to create style")
+}
@@ -1,7 +1,10 @@
 //! # GIcon
 //! helps to connect yew app with `https://fonts.google.com/icons`
 //!
-//! To use icons with the help of this crate you need to add some additional code to `index.html` inside `<head></head>`
+//! GIcon adds the stylesheet of its icon style to `<head></head>` automatically (see `fonts` module to use a self-hosted mirror or turn it off).
+//! With `svg-icons` feature the icons embedded in the crate are rendered as inline `<svg>` without the font (see `svg` module).
+//! App-specific icons registered in `GIconRegistry` are resolved by name before Material Symbols (see `registry` module).
+//! Without automatic injection you need to add some additional code to `index.html` inside `<head></head>`
 //! 
 //! There are three types of Icons. Add this if you want to use icons Outlined style:
 //! ```
@@ -16,6 +19,7 @@
 //! <link rel="stylesheet" href="https://fonts.googleapis.com/css2?family=Material+Symbols+Sharp:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200" />
 //! ```
 //! Then choose on of the icons in the catalog of `<https://fonts.google.com/icons>`, i.e. 'search', 'star', 'menu', etc. and see example below. 
+//! `icon` attribute takes `MaterialSymbol` (e.g. `MaterialSymbol::Search`, a typo is a compile error) or the icon name as a string.
 //! ## Examples
 //! ```
 //! // with <link rel="stylesheet" href="https://fonts.googleapis.com/css2?family=Material+Symbols+Outlined:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200" />
@@ -26,7 +30,7 @@
 //! <GIcon 
 //!     size="16px"
 //!     transition="all 0.2s"
-//!     icon="search" 
+//!     icon={MaterialSymbol::Search}
 //!     icon_style={GIconStyle::Outlined} 
 //!     fill=true
 //!     wght="200"
@@ -35,7 +39,33 @@
 //!     color="#fff"
 //! />
 //! ```
+//! If `color` is not set, GIcon inside `GThemeProvider` uses `on_surface_variant` color of `GTheme`, otherwise it inherits the text color.
+//! 
+//! `wght` (100..=700, default 300), `grade` (-50..=200, default 100) and `opsz` (20..=48) take numbers, strings or
+//! `IconWeight`, `IconGrade` and `OpticalSize`. Values out of range are clamped and a warning is logged to the browser console.
+//! If `opsz` is not set (or `"auto"`), it is derived from `size` (a 48px icon gets optical size 48, a 16px icon gets 20).
+//! If the size can not be resolved (e.g. `var(--icon-size)`) or the icon is inside GButton or GTextInput, the browser sets it from the real font size.
+//! 
+//! Until the icon font is loaded GIcon is an invisible placeholder of its size, so the icon name is not shown as text.
+//! If the font is not loaded in `icon_timeout` of `GFonts` (3s by default), GIcon shows `fallback` glyph (e.g. `fallback="⌕"`) or the icon name.
+//! 
 //! Note, you that you can animate icon attributes with transition. The default value is "unset", but for animation it is recomended to set "all 0.2s" or as you wish.
+//! GIcons with the same `icon_style`, `fill`, `wght`, `grade`, `opsz`, `color`, `size` and `transition` share one generated css class,
+//! so a page with hundreds of icons builds only a few styles.
+//! The transition is turned off if the OS has `prefers-reduced-motion: reduce` setting.
+//! In forced colors mode (e.g. Windows high contrast) GIcon uses `CanvasText` color, inside GButton it inherits `ButtonText` (`GrayText` if the button is disabled).
+//! 
+//! Interaction states are declared with attributes and animated with motion tokens (or with `transition` if it is set):
+//! `fill_on_hover` fills the icon on hover, `hover_grade` changes the grade on hover, `press_wght` changes the weight while pressed
+//! (inside GButton the button is hovered and pressed), `selected` fills the icon or, with `selected_icon`, cross-fades to another icon.
+//! Hover and press states change the font axes, so they have no effect on SVG and registered icons.
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//! 
+//! <GIcon icon={MaterialSymbol::Star} icon_style={GIconStyle::Rounded} fill_on_hover=true press_wght={IconWeight::from(600)} />
+//! <GIcon icon={MaterialSymbol::Menu} selected_icon={MaterialSymbol::Close} selected={menu_open} icon_style={GIconStyle::Rounded} />
+//! ```
 //! 
 //! Or you can add an icon with default options with another icons style:
 //! ```
@@ -50,25 +80,36 @@
 //! />
 //! ```
 
-use stylist::Style;
 use yew::prelude::*;
-use crate::GIconStyle;
+use crate::{fonts::{icon_font_state, inject_icon_font, unwatch_icon_font, watch_icon_font, IconFontState}, theme::{GDuration, GEasing}, GIconStyle};
 use web_sys::Element;
 
+use icon_css::{icon_style, universal_style, IconStyleKey};
+
+pub use axes::{IconGrade, IconWeight, OpticalSize};
+pub use registry::{init_icons, GIconRegistry, GIconSource};
+pub use symbols::MaterialSymbol;
+
+mod axes;
+mod icon_css;
+mod registry;
+mod svg;
+mod symbols;
+
 #[derive(Debug, Properties, PartialEq, Clone)]
 pub struct GIconProps {
     pub icon: AttrValue,
     pub icon_style: GIconStyle,
     #[prop_or_else(|| false )]
     pub fill: bool,
-    #[prop_or_else(|| AttrValue::from("300"))]
-    pub wght: AttrValue,
-    #[prop_or_else(|| AttrValue::from("100"))]
-    pub grade: AttrValue,
-    #[prop_or_else(|| AttrValue::from("24"))]
-    pub opsz: AttrValue,
-    #[prop_or_else(|| AttrValue::from(r#"inherit"#))]
-    pub color: AttrValue,
+    #[prop_or_default]
+    pub wght: IconWeight,
+    #[prop_or_default]
+    pub grade: IconGrade,
+    #[prop_or_default]
+    pub opsz: OpticalSize,
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
     #[prop_or_else(|| AttrValue::from(r#"24px"#))]
     pub size: AttrValue,
     #[prop_or_else(|| AttrValue::from("unset"))]
@@ -77,61 +118,123 @@ pub struct GIconProps {
     pub leading_icon: bool, 
     #[prop_or_default]
     pub trailing_icon: bool, 
+    #[prop_or_default]
+    pub fallback: Option<AttrValue>,
+    #[prop_or_default]
+    pub fill_on_hover: bool,
+    #[prop_or_default]
+    pub hover_grade: Option<IconGrade>,
+    #[prop_or_default]
+    pub press_wght: Option<IconWeight>,
+    #[prop_or_default]
+    pub selected: bool,
+    #[prop_or_default]
+    pub selected_icon: Option<AttrValue>,
 }
 
 #[function_component(GIcon)]
 pub fn icon(props: &GIconProps) -> Html {
-    let universal_slyle = match props.icon_style {
-        GIconStyle::Outlined => "material-symbols-outlined",
-        GIconStyle::Rounded => "material-symbols-rounded",
-        GIconStyle::Sharp => "material-symbols-sharp",
+    let universal_slyle = universal_style(&props.icon_style);
+    let wght = props.wght;
+    // icons inside GButton and GTextInput are sized by the parent, so the browser derives opsz from the real font size
+    let opsz = match props.opsz {
+        opsz if !opsz.is_auto() => Some(opsz),
+        _ if props.leading_icon || props.trailing_icon => None,
+        _ => OpticalSize::from_size(&props.size),
     };
-    let fill = if props.fill { 1_u8 } else { 0_u8 };
-    let wght = {
-        match props.wght.parse::<u16>() {
-            Ok(x) => {if x >= 100 && x <= 700 {x} else {panic!("Wrong Google Material Icon wght setting")}},
-            Err(_) => panic!("Wrong Google Material Icon wght setting"),
-        }
-    };
-    let grade = {
-        match props.grade.parse::<i16>() {
-            Ok(x) => {if x >= -50 && x <= 200 {x} else {panic!("Wrong Google Material Icon grade setting")}},
-            Err(_) => panic!("Wrong Google Material Icon grade setting"),
-        }
-    };
-    let opsz = {
-        match props.opsz.parse::<u8>() {
-            Ok(x) => {if x >= 20 && x <= 48 {x} else {panic!("Wrong Google Material Icon opsz setting")}},
-            Err(_) => panic!("Wrong Google Material Icon opsz setting"),
-        }
+    let leading_icon = props.leading_icon;
+    let trailing_icon = props.trailing_icon;
+    // `selected` either fills the icon or swaps it for `selected_icon`
+    let fill = props.fill || (props.selected && props.selected_icon.is_none());
+
+    // interaction states are animated with motion tokens unless `transition` is set
+    let animated = props.fill_on_hover || props.hover_grade.is_some() || props.press_wght.is_some() || props.selected_icon.is_some();
+    let transition = if animated && props.transition == "unset" {
+        let (duration, easing) = (GDuration::Short4.var(), GEasing::Standard.var());
+        AttrValue::from(format!("font-variation-settings {duration} {easing}, opacity {duration} {easing}"))
+    } else {
+        props.transition.clone()
     };
 
-    let color = props.color.clone();
-    let transition = props.transition.clone();
-    let size = props.size.clone();
-    let leading_icon = props.leading_icon.clone();
-    let trailing_icon = props.trailing_icon.clone();
-
-    let style_str = format!(
-        r#"
-            .{universal_slyle} {{
-                display: block;
-                transition: {transition};
-                color: {color};
-                font-size: {size};
-                font-variation-settings:
-                'FILL' {fill},
-                'wght' {wght},
-                'GRAD' {grade},
-                'opsz' {opsz}
-                }}
-        "#
-    );
+    let style = icon_style(IconStyleKey {
+        icon_style: props.icon_style.clone(),
+        fill,
+        wght,
+        grade: props.grade,
+        opsz,
+        fill_on_hover: props.fill_on_hover,
+        hover_grade: props.hover_grade,
+        press_wght: props.press_wght,
+        color: props.color.clone().unwrap_or_else(|| AttrValue::from("var(--md-sys-color-on-surface-variant, inherit)")),
+        size: props.size.clone(),
+        transition,
+    });
 
     let node_ref = NodeRef::default();
     let node_ref_clone = node_ref.clone();
 
-    let style = Style::new(style_str).expect("Failed to create style");
+    let registry = use_context::<GIconRegistry>();
+    let svg_icon = |name: &AttrValue| {
+        registry::registered_icon(registry.as_ref(), name).or_else(|| svg::svg_icon(name, fill, wght))
+    };
+    let icon = svg_icon(&props.icon);
+    let selected_icon = props.selected_icon.clone().map(|name| {
+        let svg = svg_icon(&name);
+        (name, svg)
+    });
+    let needs_font = icon.is_none() || selected_icon.as_ref().is_some_and(|(_, svg)| svg.is_none());
+
+    let font_state = use_state(|| icon_font_state(&props.icon_style));
+    let font_state_setter = font_state.setter();
+
+    // registered and SVG icons do not need the font, a GIcon waits again after each state until the font is ready
+    let current_font_state = *font_state;
+    use_effect_with((props.icon_style.clone(), needs_font, current_font_state), move |(icon_style, needs_font, _)| {
+        let mut waiter = None;
+        if *needs_font {
+            inject_icon_font(icon_style);
+            let setter = font_state_setter.clone();
+            let (state, id) = watch_icon_font(icon_style, Callback::from(move |state| setter.set(state)));
+            if state != current_font_state {
+                font_state_setter.set(state);
+            }
+            waiter = id;
+        }
+        let icon_style = icon_style.clone();
+        move || {
+            if let Some(id) = waiter {
+                unwatch_icon_font(&icon_style, id);
+            }
+        }
+    });
+
+    // the icon name is hidden until the font is loaded, so the ligature text does not flash
+    let content = |name: &AttrValue, svg_icon: Option<Html>| match (svg_icon, *font_state) {
+        (Some(svg_icon), _) => (svg_icon, None),
+        (None, IconFontState::Ready) => (html! { name.clone() }, None),
+        (None, IconFontState::Loading) => (html! {}, Some("g_icon_loading")),
+        (None, IconFontState::TimedOut) => match props.fallback.clone() {
+            Some(fallback) => (html! { fallback }, Some("g_icon_fallback")),
+            None => (html! { name.clone() }, None),
+        },
+    };
+
+    let (icon_content, icon_class) = content(&props.icon, icon);
+    let spans = match selected_icon {
+        // both icons are rendered on top of each other and cross-faded with opacity
+        Some((selected_name, selected_svg)) => {
+            let (selected_content, selected_class) = content(&selected_name, selected_svg);
+            let selected = props.selected;
+            html! {
+                <>
+                    <span class={classes!(universal_slyle, icon_class, "g_icon_swap", (!selected).then_some("g_icon_shown"))} aria-hidden={selected.to_string()}>{icon_content}</span>
+                    <span class={classes!(universal_slyle, selected_class, "g_icon_swap", selected.then_some("g_icon_shown"))} aria-hidden={(!selected).to_string()}>{selected_content}</span>
+                </>
+            }
+        }
+        None => html! { <span class={classes!(universal_slyle, icon_class)}>{icon_content}</span> },
+    };
+    let div_style = if props.selected_icon.is_some() { "line-height: 0; position: relative" } else { "line-height: 0" };
 
     use_effect(move || {
         if leading_icon || trailing_icon {
@@ -148,8 +251,8 @@ pub fn icon(props: &GIconProps) -> Html {
 
     html! {
         <gicon ref={node_ref} style="line-height: 0">
-            <div class={style} style="line-height: 0">
-                <span class={universal_slyle}>{props.icon.clone()}</span>
+            <div class={style} style={div_style}>
+                {spans}
             </div>
         </gicon>
     }
@@ -0,0 +1,100 @@
+//! # GIconRegistry
+//! holds app-specific SVG icons which are not in Material Symbols.
+//!
+//! `GIcon` (also the icons of `GButton` `has_icon` and icons inside `GTextInput`) looks for its `icon` name in the registry first:
+//! in `GIconRegistry` context of the nearest `ContextProvider`, then in the registry set by `init_icons`.
+//! Names which are not registered are rendered with Material Symbols as before.
+//!
+//! Registered icons get `size`, `color` and the icon placement of GButton and GTextInput like the built-in ones,
+//! `fill`, `wght`, `grade` and `opsz` have no effect on them. Icon source can be:
+//! - `GIconSource::path(view_box, d)`: path data, painted with the icon color,
+//! - `GIconSource::svg(markup)`: full `<svg>` markup, it is inserted as is, so use `currentColor` to follow the icon color.
+//!
+//! Register only trusted markup, it is not sanitized.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! fn main() {
+//!     init_icons(
+//!         GIconRegistry::default()
+//!             .with("brand_logo", GIconSource::path("0 0 24 24", "M12 2L2 22h20z"))
+//!             .with("kiosk_card", GIconSource::svg(r#"<svg viewBox="0 0 24 24"><rect x="3" y="6" width="18" height="12" rx="2" fill="currentColor"/></svg>"#)),
+//!     );
+//!     yew::Renderer::<App>::new().render();
+//! }
+//!
+//! <GIcon icon="brand_logo" icon_style={GIconStyle::Outlined} size="32px" color="#6750a4" />
+//! <GButton id="pay_button" label="Pay" has_icon="kiosk_card" icon_style={GIconStyle::Outlined} />
+//!
+//! // or for a subtree
+//! let registry = GIconRegistry::default().with("brand_logo", GIconSource::path("0 0 24 24", "M12 2L2 22h20z"));
+//! <ContextProvider<GIconRegistry> context={registry}>
+//!     <GIcon icon="brand_logo" icon_style={GIconStyle::Outlined} />
+//! </ContextProvider<GIconRegistry>>
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+thread_local! {
+    static ICONS: RefCell<GIconRegistry> = RefCell::new(GIconRegistry::default());
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GIconSource {
+    Path { view_box: AttrValue, d: AttrValue },
+    Svg(AttrValue),
+}
+
+impl GIconSource {
+    pub fn path(view_box: impl Into<AttrValue>, d: impl Into<AttrValue>) -> Self {
+        GIconSource::Path { view_box: view_box.into(), d: d.into() }
+    }
+
+    pub fn svg(markup: impl Into<AttrValue>) -> Self {
+        GIconSource::Svg(markup.into())
+    }
+
+    pub(crate) fn html(&self) -> Html {
+        match self {
+            GIconSource::Path { view_box, d } => html! {
+                <svg viewBox={view_box.clone()} width="1em" height="1em" fill="currentColor" aria-hidden="true">
+                    <path d={d.clone()} />
+                </svg>
+            },
+            GIconSource::Svg(markup) => Html::from_html_unchecked(markup.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct GIconRegistry(Rc<HashMap<AttrValue, GIconSource>>);
+
+impl GIconRegistry {
+    pub fn with(mut self, name: impl Into<AttrValue>, source: GIconSource) -> Self {
+        Rc::make_mut(&mut self.0).insert(name.into(), source);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&GIconSource> {
+        self.0.get(name)
+    }
+}
+
+pub fn init_icons(registry: GIconRegistry) {
+    ICONS.with(|icons| *icons.borrow_mut() = registry);
+}
+
+/// Icon from the context registry, then from the `init_icons` one
+pub(crate) fn registered_icon(context: Option<&GIconRegistry>, name: &str) -> Option<Html> {
+    if let Some(source) = context.and_then(|registry| registry.get(name)) {
+        return Some(source.html());
+    }
+    ICONS.with(|icons| icons.borrow().get(name).map(GIconSource::html))
+}
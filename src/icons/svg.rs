@@ -0,0 +1,96 @@
+//! # SVG icons
+//! With `svg-icons` feature `GIcon` renders inline `<svg>` instead of the ligature of Material Symbols font,
+//! so the app has no font dependency (e.g. offline builds) and the icon name is never shown while the font is loading.
+//!
+//! Path data is embedded from `src/icons/svg_paths` (see `build.rs` for the format), only the icons listed there are compiled in.
+//! Add lines to the file for the icons your app uses. An icon which is not in the file is rendered with the font as before.
+//!
+//! `fill` chooses the filled or outlined variant, `wght` chooses the variant with the nearest weight,
+//! `color` and `size` are applied as for the font icon. `grade` and `opsz` have no effect on SVG icons.
+//!
+//! Limitations of the vendored file: every icon has only the weight 400 variant (so `wght` does not change it yet)
+//! and one shape for all `icon_style`s, the paths are 24x24 Material Icons drawings.
+//! Add `name fill wght path` lines with other weights (e.g. 100 and 700) to get them picked by `wght`.
+//!
+//! ## Examples
+//! ```
+//! // Cargo.toml
+//! // yew-google-material = { version = "0.0.9", features = ["svg-icons"] }
+//!
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! <GIcon icon={MaterialSymbol::Favorite} icon_style={GIconStyle::Outlined} fill=true color="#b3261e" size="32px" />
+//! ```
+
+use yew::prelude::*;
+
+use super::IconWeight;
+
+#[cfg(feature = "svg-icons")]
+include!(concat!(env!("OUT_DIR"), "/svg_paths.rs"));
+
+/// Path data of the variant of `name` with the same fill and the nearest weight
+#[cfg(feature = "svg-icons")]
+fn svg_path(name: &str, fill: bool, wght: IconWeight) -> Option<&'static str> {
+    nearest_variant(SVG_PATHS, name, fill, wght)
+}
+
+#[cfg(any(test, feature = "svg-icons"))]
+fn nearest_variant(paths: &[(&str, Option<bool>, u16, &'static str)], name: &str, fill: bool, wght: IconWeight) -> Option<&'static str> {
+    paths
+        .iter()
+        .filter(|(icon, variant_fill, ..)| *icon == name && *variant_fill != Some(!fill))
+        // a variant made for this fill wins over a shared one
+        .min_by_key(|(_, variant_fill, variant_wght, _)| (variant_fill.is_none(), variant_wght.abs_diff(wght.value())))
+        .map(|(.., path)| *path)
+}
+
+/// `None` if the icon is not embedded, 1em follows the font size of the icon span,
+/// so `size` and the icon sizes of GButton and GTextInput apply
+#[cfg(feature = "svg-icons")]
+pub(crate) fn svg_icon(name: &str, fill: bool, wght: IconWeight) -> Option<Html> {
+    svg_path(name, fill, wght).map(|path| {
+        html! {
+            <svg viewBox="0 0 24 24" width="1em" height="1em" fill="currentColor" aria-hidden="true" style="display: block">
+                <path d={path} />
+            </svg>
+        }
+    })
+}
+
+#[cfg(not(feature = "svg-icons"))]
+pub(crate) fn svg_icon(_name: &str, _fill: bool, _wght: IconWeight) -> Option<Html> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATHS: &[(&str, Option<bool>, u16, &str)] = &[
+        ("star", None, 400, "shared 400"),
+        ("star", Some(true), 100, "filled 100"),
+        ("star", Some(true), 700, "filled 700"),
+        ("home", Some(false), 100, "outlined 100"),
+        ("home", Some(false), 400, "outlined 400"),
+        ("home", Some(false), 700, "outlined 700"),
+        ("home", Some(true), 400, "filled 400"),
+    ];
+
+    #[test]
+    fn fill_specific_variant_wins() {
+        assert_eq!(nearest_variant(PATHS, "star", true, IconWeight::from(300)), Some("filled 100"));
+        assert_eq!(nearest_variant(PATHS, "star", false, IconWeight::from(700)), Some("shared 400"));
+        assert_eq!(nearest_variant(PATHS, "home", true, IconWeight::from(700)), Some("filled 400"));
+    }
+
+    #[test]
+    fn nearest_weight_is_picked() {
+        assert_eq!(nearest_variant(PATHS, "home", false, IconWeight::from(100)), Some("outlined 100"));
+        assert_eq!(nearest_variant(PATHS, "home", false, IconWeight::from(300)), Some("outlined 400"));
+        assert_eq!(nearest_variant(PATHS, "home", false, IconWeight::from(600)), Some("outlined 700"));
+        assert_eq!(nearest_variant(PATHS, "star", true, IconWeight::from(500)), Some("filled 700"));
+        assert_eq!(nearest_variant(PATHS, "search", false, IconWeight::default()), None);
+    }
+}
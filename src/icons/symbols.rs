@@ -0,0 +1,67 @@
+//! # MaterialSymbol
+//! is a typed name of an icon from `https://fonts.google.com/icons`, so a typo in the icon name is a compile error.
+//!
+//! The enum is generated by `build.rs` from the Material Symbols codepoints list vendored in `src/icons/codepoints`
+//! (`name codepoint` per line, the format of `.codepoints` files of `https://github.com/google/material-design-icons`).
+//! The vendored list is a subset with the most used symbols, not the full upstream file.
+//! To get the whole catalog download the upstream file (its url is in the header of `src/icons/codepoints`)
+//! and point `MATERIAL_SYMBOLS_CODEPOINTS` environment variable to it at build time, or add lines to the vendored list.
+//!
+//! `GIcon` `icon` and `GButton` `has_icon` attributes accept both `MaterialSymbol` and a string.
+//! Any icon name passed as a string is rendered as is, so every symbol of `https://fonts.google.com/icons` works without the enum.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! <GIcon icon={MaterialSymbol::Search} icon_style={GIconStyle::Outlined} />
+//! <GButton id="menu_button" has_icon={MaterialSymbol::Menu} icon_style={GIconStyle::Rounded} />
+//! <GIcon icon="new_icon_name" icon_style={GIconStyle::Outlined} />
+//!
+//! let search: MaterialSymbol = "search".parse().unwrap();
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use yew::html::IntoPropValue;
+use yew::AttrValue;
+
+include!(concat!(env!("OUT_DIR"), "/material_symbols.rs"));
+
+impl FromStr for MaterialSymbol {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        MaterialSymbol::ALL
+            .iter()
+            .find(|symbol| symbol.name() == name)
+            .copied()
+            .ok_or_else(|| format!("Material Symbol {name} is not in the vendored subset, pass the name as a string instead"))
+    }
+}
+
+impl fmt::Display for MaterialSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<MaterialSymbol> for AttrValue {
+    fn from(symbol: MaterialSymbol) -> Self {
+        AttrValue::Static(symbol.name())
+    }
+}
+
+impl IntoPropValue<AttrValue> for MaterialSymbol {
+    fn into_prop_value(self) -> AttrValue {
+        AttrValue::from(self)
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for MaterialSymbol {
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self))
+    }
+}
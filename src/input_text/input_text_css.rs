@@ -1,7 +1,7 @@
 use stylist::Style;
 use yew::prelude::*;
 
-use crate::GInputStyle;
+use crate::{theme::{density_size, sys_color, GDensity, GDuration, GEasing, GShape, GShapeCorner, GState, GStateLayer, GTypescale, FORCED_COLORS_MEDIA, REDUCED_MOTION_MEDIA}, GInputStyle};
 
 #[allow(non_upper_case_globals)]
 pub(super) fn input_style(
@@ -12,105 +12,112 @@ pub(super) fn input_style(
     g_input_width: AttrValue, 
     g_input_height: Option<AttrValue>, 
     g_font_size: AttrValue, 
-    g_input_border_radius: AttrValue, 
+    g_input_shape: GShape, 
     g_input_border_color: AttrValue, 
     g_input_border_color_hover: AttrValue,
     g_input_border_focus_color: AttrValue, 
     mut g_label_background_color: AttrValue, 
     g_label_text_color: AttrValue, 
     g_align_supporting_text: AttrValue,
-    g_supporting_text_color: Option<AttrValue>,
+    g_supporting_text_color: AttrValue,
     no_asterisk: bool,
     has_leading_icon: bool,
     has_trailing_icon: bool,
     no_spinner: Option<bool>,
+    density: Option<GDensity>,
 ) -> Style {
-    let g_supporting_text_color = if let Some(value) = g_supporting_text_color {
-        AttrValue::from(format!("color: {value};"))
-    } else {
-        AttrValue::default()
-    };
-    let input_padding_left;
-    let label_left;
+    let g_font_family = GTypescale::BodyLarge.var("font");
+    let g_letter_spacing = GTypescale::BodyLarge.var("tracking");
+    let g_supporting_text_letter_spacing = GTypescale::BodySmall.var("tracking");
+    let g_input_text_color = sys_color("on-surface");
+    let on_surface = GStateLayer::new(&g_input_text_color);
+    let g_disabled_text_color = on_surface.with_opacity(0.38);
+    let g_disabled_border_color = on_surface.with_opacity(0.12);
+    let input_padding_start;
+    let label_start;
     if has_leading_icon {
-        input_padding_left = "2.75em";
-        label_left = "2.75em";
+        input_padding_start = "2.75em";
+        label_start = "2.75em";
     } else {
-        input_padding_left = "1em";
-        label_left = "1em";
+        input_padding_start = "1em";
+        label_start = "1em";
     }
-    let input_padding_right;
+    let input_padding_end;
     if has_trailing_icon {
-        input_padding_right = "2.75em";
+        input_padding_end = "2.75em";
     } else {
-        input_padding_right = "0";
+        input_padding_end = "0";
     }
 
     let g_container_margin: AttrValue;
     let input_border: AttrValue;
     let input_border_onfocus: AttrValue;
-    let input_border_bottom_radius: AttrValue;
+    let input_shape: GShape;
     let input_padding_top: AttrValue;
     let input_padding_bottom: AttrValue;
     let input_background_color: AttrValue;
     let input_background_color_onfocus: AttrValue;
     let input_background_color_onhover: AttrValue;
-    let label_on_focus_left: AttrValue;
+    let label_on_focus_start: AttrValue;
     let label_on_focus_top: AttrValue;
 
     if *style == GInputStyle::Outlined {
         g_container_margin = AttrValue::from("0.5em 0 0.2em");
         input_border = AttrValue::from("0.0625em solid");
         input_border_onfocus = AttrValue::from("0.1875em solid");
-        input_border_bottom_radius = g_input_border_radius.clone();
-        input_padding_top = AttrValue::from("1.1875em");
-        input_padding_bottom = AttrValue::from("1.1875em");
+        input_shape = g_input_shape;
+        input_padding_top = density_size("1.1875em", "0.125em", density);
+        input_padding_bottom = density_size("1.1875em", "0.125em", density);
         input_background_color = AttrValue::from("unset");
         input_background_color_onfocus = AttrValue::default();
         input_background_color_onhover = AttrValue::default();
-        label_on_focus_left = AttrValue::from("0.75em");
+        label_on_focus_start = AttrValue::from("0.75em");
         label_on_focus_top = AttrValue::from("-0.55em");
     } else {
         g_container_margin = AttrValue::from("0.5em 0 0.2em");
         input_border = AttrValue::from("0 none");
         input_border_onfocus = AttrValue::from("0 none");
-        input_border_bottom_radius = AttrValue::from("0");
+        input_shape = g_input_shape.bottom(GShapeCorner::None);
         if no_asterisk {
-            input_padding_top = AttrValue::from("1.1875em");
-            input_padding_bottom = AttrValue::from("1.1875em");
+            input_padding_top = density_size("1.1875em", "0.125em", density);
+            input_padding_bottom = density_size("1.1875em", "0.125em", density);
         } else {
-            input_padding_top = AttrValue::from("1.25em");
-            input_padding_bottom = AttrValue::from("0.5em");
+            input_padding_top = density_size("1.25em", "0.125em", density);
+            input_padding_bottom = density_size("0.5em", "0.125em", density);
         }
-        input_background_color = AttrValue::from("whitesmoke");
-        input_background_color_onfocus = AttrValue::from("background-color: #eeeeee;");
-        input_background_color_onhover = AttrValue::from("background-color: #eeeeee;");
+        input_background_color = sys_color("surface-variant");
+        input_background_color_onfocus = AttrValue::from(format!("background-color: {};", on_surface.over(&input_background_color, GState::Focus)));
+        input_background_color_onhover = AttrValue::from(format!("background-color: {};", on_surface.over(&input_background_color, GState::Hover)));
         g_label_background_color = AttrValue::from("transparent");
         if has_leading_icon {
-            label_on_focus_left = AttrValue::from("3.5em");
+            label_on_focus_start = AttrValue::from("3.5em");
         } else {
-            label_on_focus_left = AttrValue::from("1.125em");
+            label_on_focus_start = AttrValue::from("1.125em");
         }
-        label_on_focus_top = AttrValue::from("0.5em");
+        label_on_focus_top = density_size("0.5em", "0.0625em", density);
     }
 
     let height: String;
     let label_top: String;
     let icon_top: String;
     if g_input_height.is_none() {
-        height = "3.5em".to_string();
-        label_top = "1.1875em".to_string();
-        icon_top = "1em".to_string();
+        height = density_size("3.5em", "0.25em", density).to_string();
+        label_top = density_size("1.1875em", "0.125em", density).to_string();
+        icon_top = density_size("1em", "0.125em", density).to_string();
     } else {
         let (height_digit, height_text) = crate::parse_number_and_unit(g_input_height.clone().unwrap());
         height = g_input_height.unwrap().to_string();
         label_top = format!("{}{}", (height_digit / 2.95), height_text);
         icon_top = format!("{}{}", (height_digit / 3.5), height_text);
     }
+    let input_shape_css = input_shape.css();
+    let g_transition = format!("{} {}", GDuration::Short4.var(), GEasing::Standard.var());
     let mut style_str = format!(
         r#"
         #{g_init} {{
             font-size: {g_font_size}; 
+            font-family: {g_font_family};
+            letter-spacing: {g_letter_spacing};
             width: {g_input_width};    
             box-sizing: border-box;
             width: 100%;
@@ -126,18 +133,21 @@ pub(super) fn input_style(
         input#{id} {{
             width: 100%;
             height: {height};
-            padding: {input_padding_top} {input_padding_right} {input_padding_bottom} {input_padding_left};
+            padding-block: {input_padding_top} {input_padding_bottom};
+            padding-inline: {input_padding_start} {input_padding_end};
             margin: 0;
-            transition: border 0.2s;
+            transition: border {g_transition};
             line-height: 1em;
             border: {input_border};
             border-bottom: 0.0625em solid;
             border-color: {g_input_border_color};
-            border-radius: {input_border_bottom_radius};
-            border-top-left-radius: {g_input_border_radius};
-            border-top-right-radius: {g_input_border_radius};
+            {input_shape_css}
             background-color: {input_background_color};
+            color: {g_input_text_color};
+            caret-color: {g_input_border_focus_color};
             font-size: 1em;
+            font-family: inherit;
+            letter-spacing: inherit;
             box-sizing: border-box;
             resize: vertical;
         }}
@@ -163,27 +173,78 @@ pub(super) fn input_style(
             border-bottom: 0.0625em solid;
             border-color: {g_input_border_color};
         }}
+
+        input#{id}:disabled {{
+            color: {g_disabled_text_color};
+            border-color: {g_disabled_border_color};
+        }}
+
+        input#{id}:disabled + label {{
+            color: {g_disabled_text_color};
+        }}
         
         #{g_container} > label {{
             position: absolute;
             top: {label_top};
-            left: {label_left};
+            inset-inline-start: {label_start};
             line-height: 1em;
             background-color: {g_label_background_color}; 
             padding: 0;
             font-size: 1em;
             color: {g_label_text_color};
-            transition: 0.2s;
+            transition: all {g_transition};
             pointer-events: none;
         }}
 
         .g_supporting_text_below_input_text_field {{
             text-align: {g_align_supporting_text};
             font-size: 0.75em;
+            letter-spacing: {g_supporting_text_letter_spacing};
             width: calc({g_input_width} - 2em);
             margin: 0;
             padding: 0 1em;
-            {g_supporting_text_color}
+            color: {g_supporting_text_color};
+        }}
+
+        {REDUCED_MOTION_MEDIA} {{
+            input#{id}, #{g_container} > label {{
+                transition: none;
+            }}
+        }}
+
+        {FORCED_COLORS_MEDIA} {{
+            input#{id}, input#{id}:not(:focus) {{
+                border-color: ButtonText;
+            }}
+
+            input#{id}:hover {{
+                border-color: Highlight;
+            }}
+
+            input#{id}:focus {{
+                border-color: Highlight;
+                outline: 0.125em solid Highlight;
+                outline-offset: 0.125em;
+            }}
+
+            input#{id}:disabled {{
+                color: GrayText;
+                border-color: GrayText;
+            }}
+
+            #{g_container} > label {{
+                forced-color-adjust: none;
+                background-color: Canvas;
+                color: CanvasText;
+            }}
+
+            input#{id}:focus + label {{
+                color: Highlight;
+            }}
+
+            input#{id}:disabled + label {{
+                color: GrayText;
+            }}
         }}
         "#
     );
@@ -195,7 +256,7 @@ pub(super) fn input_style(
             display: block;
             position: absolute;  
             top: {icon_top};
-            left: 0.5625em;
+            inset-inline-start: 0.5625em;
             pointer-events: none;
         }}
 
@@ -212,7 +273,7 @@ pub(super) fn input_style(
             display: block;
             position: absolute;
             top: {icon_top};
-            right: 0.5625em;
+            inset-inline-end: 0.5625em;
             pointer-events: none;
         }}
 
@@ -231,7 +292,7 @@ pub(super) fn input_style(
             padding: 0 0.25em;
             line-height: 0.75em;
             top: {label_on_focus_top};
-            left: {label_on_focus_left};
+            inset-inline-start: {label_on_focus_start};
             font-size: 0.75em;
             color: {g_input_border_focus_color};
         }}
@@ -2,7 +2,7 @@
 //! is similar to google material text field (but not identical) `https://material-web.dev/components/text-field`
 //! It allows you to choose style, add leading and/or trailing icons, or leading and/or trailing icon buttons. 
 //! 
-//! The key size attribute of input field is `font_size`. It bonds a lot of other sizes of input text field and has the default value 16px. 
+//! The key size attribute of input field is `font_size`. It bonds a lot of other sizes of input text field and has the default value 16px (`body-large` role of the type scale, see `GTypescale`). 
 //! According to this 1px here = 0.0625em
 //! 
 //! GTextInput has a lot of attributes, but only `id`, onchange and `label` are required. Label here has the same role as placeholder. If you do not need `label`, add it with empty double quotes `""`.
@@ -51,25 +51,27 @@
 //! - width: `AttrValue`, 
 //! [default "100%"]
 //! - height: `Option<AttrValue>`
-//! [default 3.5em] Be careful to change this! It can break the sizes of text field. Better use `em`, e.g. `3.4em` or `2em`
-//! - font_size: `AttrValue`, 
-//! [default "16px"]
-//! - border_radius: `AttrValue`, 
-//! [default "4px"] It is similar to container_shape in google material buttons
-//! - border_color: `AttrValue`, 
-//! [default "grey"]
-//! - border_color_hover: `AttrValue`, 
-//! [default "black"]
-//! - border_focus_color: `AttrValue`, 
-//! [default "#6200ee"]
-//! - label_background_color: `AttrValue`, 
-//! [default "white"]
-//! - label_text_color: `AttrValue`, 
-//! [default "#aaa"]
+//! [default 3.5em] 56px with 16px font size, 4px lower for every step of `density`. Better use `density` to make the text field smaller, an explicit height wins over it. Use `em`, e.g. `3.4em` or `2em`
+//! - density: `Option<GDensity>`
+//! [default None] Uses `density` of `GTheme` (see `GDensity`)
+//! - font_size: `Option<AttrValue>`, 
+//! [default None] Uses `--md-sys-typescale-body-large-size` token of `GThemeProvider` (16px)
+//! - shape: `GShape`, 
+//! [default GShapeCorner::ExtraSmall] Shape token of the theme shape scale (see `GShape`). Filled text field uses only top corners of the shape
+//! - border_color: `Option<AttrValue>`, 
+//! [default None] Uses `outline` color of `GTheme`
+//! - border_color_hover: `Option<AttrValue>`, 
+//! [default None] Uses `on_surface` color of `GTheme`
+//! - border_focus_color: `Option<AttrValue>`, 
+//! [default None] Uses `primary` color of `GTheme`
+//! - label_background_color: `Option<AttrValue>`, 
+//! [default None] Uses `surface` color of `GTheme`
+//! - label_text_color: `Option<AttrValue>`, 
+//! [default None] Uses `on_surface_variant` color of `GTheme`
 //! - align_supporting_text: `AttrValue`, 
-//! [default "left"]
+//! [default "start"] `start` and `end` follow the text direction
 //! - supporting_text_color: `Option<AttrValue>`, 
-//! [default None] e.g. `black` or `red` or `#ffffff`
+//! [default None] e.g. `black` or `red` or `#ffffff`. Uses `on_surface_variant` color of `GTheme` if None
 //! - supporting_text: `Option<AttrValue>`, 
 //! [default None] e.g. `*required` or `Error`
 //! - no_asterisk: `bool`, 
@@ -125,7 +127,7 @@
 //! ```
 //! 
 //! If you need to add trailing button icon inside input field, instead of `GIcon` use `GButton` inside `<GTextInput></GTextInput>` with attributes:
-//! `has_icon` (icon name from `fonts.google.com/icons`), `trailing_icon` (`true`), `parent` (`DependsOn::GTextInput`), `icon_style` (Outlined, Rounded or Sharp)
+//! `has_icon` (icon name from `fonts.google.com/icons` or `GIconRegistry`), `trailing_icon` (`true`), `parent` (`DependsOn::GTextInput`), `icon_style` (Outlined, Rounded or Sharp)
 //! 
 //! Do not use `label` attribute for `GButton` inside `GTextInput`!
 //! ```
@@ -155,7 +157,7 @@ pub(crate) mod input_text_css;
 use web_sys::HtmlInputElement;
 use yew::{prelude::*, virtual_dom::VNode};
 
-use crate::{input_text::input_text_css::input_style, GInputStyle};
+use crate::{fonts::inject_roboto, input_text::input_text_css::input_style, theme::{sys_color, GDensity, GShape, GShapeCorner, GTheme, GTypescale}, GInputStyle};
 
 #[derive(Debug, Clone)]
 pub enum Msg {
@@ -220,21 +222,23 @@ pub struct GTextInputProps {
     pub width: AttrValue, 
     #[prop_or_default]
     pub height: Option<AttrValue>, 
-    #[prop_or_else(|| AttrValue::from("16px"))]
-    pub font_size: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("4px"))]
-    pub border_radius: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("grey"))]
-    pub border_color: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("black"))]
-    pub border_color_hover: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("#6200ee"))]
-    pub border_focus_color: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("white"))]
-    pub label_background_color: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("#aaa"))]
-    pub label_text_color: AttrValue, 
-    #[prop_or_else(|| AttrValue::from("left"))]
+    #[prop_or_default]
+    pub density: Option<GDensity>, 
+    #[prop_or_default]
+    pub font_size: Option<AttrValue>, 
+    #[prop_or_else(|| GShape::from(GShapeCorner::ExtraSmall))]
+    pub shape: GShape, 
+    #[prop_or_default]
+    pub border_color: Option<AttrValue>, 
+    #[prop_or_default]
+    pub border_color_hover: Option<AttrValue>, 
+    #[prop_or_default]
+    pub border_focus_color: Option<AttrValue>, 
+    #[prop_or_default]
+    pub label_background_color: Option<AttrValue>, 
+    #[prop_or_default]
+    pub label_text_color: Option<AttrValue>, 
+    #[prop_or_else(|| AttrValue::from("start"))]
     pub align_supporting_text: AttrValue, 
     #[prop_or_default]
     pub supporting_text_color: Option<AttrValue>, 
@@ -317,19 +321,20 @@ impl Component for GTextInput {
             g_container.clone(),
             ctx.props().width.clone(), 
             ctx.props().height.clone(), 
-            ctx.props().font_size.clone(), 
-            ctx.props().border_radius.clone(), 
-            ctx.props().border_color.clone(), 
-            ctx.props().border_color_hover.clone(), 
-            ctx.props().border_focus_color.clone(), 
-            ctx.props().label_background_color.clone(), 
-            ctx.props().label_text_color.clone(), 
+            ctx.props().font_size.clone().unwrap_or_else(|| GTypescale::BodyLarge.var("size")), 
+            ctx.props().shape, 
+            ctx.props().border_color.clone().unwrap_or_else(|| sys_color("outline")), 
+            ctx.props().border_color_hover.clone().unwrap_or_else(|| sys_color("on-surface")), 
+            ctx.props().border_focus_color.clone().unwrap_or_else(|| sys_color("primary")), 
+            ctx.props().label_background_color.clone().unwrap_or_else(|| sys_color("surface")), 
+            ctx.props().label_text_color.clone().unwrap_or_else(|| sys_color("on-surface-variant")), 
             ctx.props().align_supporting_text.clone(), 
-            ctx.props().supporting_text_color.clone(),
+            ctx.props().supporting_text_color.clone().unwrap_or_else(|| sys_color("on-surface-variant")),
             ctx.props().no_asterisk.clone(), 
             ctx.props().has_leading_icon.clone(), 
             ctx.props().has_trailing_icon.clone(), 
             ctx.props().no_spinner.clone(), 
+            ctx.props().density, 
         );
         let onfocus = ctx.link().callback(|_| Msg::InputTextInit);
         let onchange = ctx.link().callback(|_| Msg::InputTextOnchange);
@@ -389,4 +394,11 @@ impl Component for GTextInput {
             </stl>
         }
     }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        // inside GThemeProvider Roboto is injected by the provider if the theme typography uses it
+        if first_render && ctx.link().context::<GTheme>(Callback::noop()).is_none() {
+            inject_roboto();
+        }
+    }
 }
\ No newline at end of file
@@ -6,18 +6,23 @@
 //!  
 //! Here I use only Rust code to add some design features for yew. 
 //! 
-//! Now only buttons, text fields and icons are available.
+//! Now only buttons, text fields, icons and text are available.
 //! Buttons and text fields are not the same as one in google material web, but very similar to them. 
 //! 
-//! See more information in `GButton`, `GIcon` and `GTextInput` modules below.
+//! See more information in `GButton`, `GIcon`, `GText` and `GTextInput` modules below.
+//! 
+//! To restyle all components at once wrap your app into `GThemeProvider` (see `theme` module).
 
 use yew::AttrValue;
 
 pub mod icons;
 pub mod input_text;
 pub mod buttons;
+pub mod theme;
+pub mod text;
+pub mod fonts;
 
-#[derive(Default, Debug, PartialEq, Clone)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GIconStyle {
     #[default]
     Outlined,
@@ -42,12 +47,17 @@ pub enum GButtonStyle {
 }
 
 pub mod prelude {
-    pub use crate::icons::GIcon;
+    pub use crate::fonts::{init_fonts, GFonts};
+    pub use crate::icons::{GIcon, MaterialSymbol, IconWeight, IconGrade, OpticalSize, GIconRegistry, GIconSource, init_icons};
     pub use crate::GIconStyle;
     pub use crate::input_text::{GTextInput, GInputEvent};
     pub use crate::GInputStyle;
     pub use crate::buttons::{GButton, DependsOn};
     pub use crate::GButtonStyle;
+    pub use crate::text::GText;
+    #[cfg(feature = "serde")]
+    pub use crate::theme::{GThemeBuilderExport, GThemeBuilderSchemes};
+    pub use crate::theme::{GThemeProvider, GTheme, GThemeMode, GDirection, GThemeSwitcher, use_theme_switcher, GColorRoles, GElevation, GState, GStateLayer, GColorRole, GColorValue, GThemeOverrides, GColorScheme, GCorePalettes, GTonalPalette, GTypescale, GTypescaleStyle, GTypography, GShape, GShapeCorner, GShapeScale, GCornerFamily, GDuration, GEasing, GDensity};
 }
 
 fn parse_number_and_unit(input: AttrValue) -> (f64, String) {
@@ -76,4 +86,12 @@ fn parse_number_and_unit(input: AttrValue) -> (f64, String) {
             (number, "".to_string())
         }, 
     }
-}
\ No newline at end of file
+}
+
+/// Browser console warning, stderr outside the browser (server side rendering and tests)
+pub(crate) fn warn(message: &str) {
+    #[cfg(target_arch = "wasm32")]
+    web_sys::console::warn_1(&message.into());
+    #[cfg(not(target_arch = "wasm32"))]
+    eprintln!("{message}");
+}
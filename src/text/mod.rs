@@ -0,0 +1,103 @@
+//! # GText
+//! renders text in one of the Material 3 type scale roles (see `GTypescale`), so headings and body copy match buttons and text fields.
+//!
+//! Font family, size, line height, weight and letter spacing come from `--md-sys-typescale-*` tokens of `GThemeProvider`.
+//! Without a provider Material 3 baseline values are used.
+//!
+//! All attributes with default parameters:
+//! - role: `GTypescale`,
+//! [default GTypescale::BodyMedium]
+//! - tag: `Option<AttrValue>`,
+//! [default None] Uses `h1` for display, `h2` for headline, `h3` for title, `p` for body and `span` for label roles
+//! - color: `Option<AttrValue>`,
+//! [default None] Uses `on_surface` color of `GTheme` inside `GThemeProvider`, otherwise inherits the text color
+//! - id: `Option<AttrValue>`,
+//! [default None]
+//! - class: `AttrValue`,
+//! [default ""]
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! <GText role={GTypescale::HeadlineMedium}>{"Sign in"}</GText>
+//! <GText>{"Use your account"}</GText>
+//! <GText role={GTypescale::LabelSmall} tag="label" color="#B3261E">{"Required"}</GText>
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use stylist::Style;
+use yew::prelude::*;
+
+use crate::{fonts::inject_roboto, theme::{GTheme, GTypescale}};
+
+thread_local! {
+    static TEXT_STYLES: RefCell<HashMap<(GTypescale, AttrValue), Style>> = RefCell::new(HashMap::new());
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GTextProps {
+    #[prop_or_default]
+    pub role: GTypescale,
+    #[prop_or_default]
+    pub tag: Option<AttrValue>,
+    #[prop_or_default]
+    pub color: Option<AttrValue>,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: AttrValue,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(GText)]
+pub fn text(props: &GTextProps) -> Html {
+    let tag = props.tag.clone().unwrap_or_else(|| AttrValue::from(match props.role {
+        GTypescale::DisplayLarge | GTypescale::DisplayMedium | GTypescale::DisplaySmall => "h1",
+        GTypescale::HeadlineLarge | GTypescale::HeadlineMedium | GTypescale::HeadlineSmall => "h2",
+        GTypescale::TitleLarge | GTypescale::TitleMedium | GTypescale::TitleSmall => "h3",
+        GTypescale::BodyLarge | GTypescale::BodyMedium | GTypescale::BodySmall => "p",
+        GTypescale::LabelLarge | GTypescale::LabelMedium | GTypescale::LabelSmall => "span",
+    }));
+    let color = props.color.clone().unwrap_or_else(|| AttrValue::from("var(--md-sys-color-on-surface, inherit)"));
+    let style = text_style(props.role, color);
+
+    // inside GThemeProvider Roboto is injected by the provider if the theme typography uses it
+    let has_theme = use_context::<GTheme>().is_some();
+    use_effect_with(has_theme, |has_theme| {
+        if !has_theme {
+            inject_roboto();
+        }
+    });
+
+    html! {
+        <@{tag.to_string()} id={props.id.clone()} class={classes!(style, props.class.clone())}>
+            {props.children.clone()}
+        </@>
+    }
+}
+
+/// Texts with the same role and color share one class
+fn text_style(role: GTypescale, color: AttrValue) -> Style {
+    TEXT_STYLES.with(|styles| {
+        styles
+            .borrow_mut()
+            .entry((role, color))
+            .or_insert_with_key(|(role, color)| {
+                let style_str = format!(
+                    r#"
+                        {}
+                        color: {color};
+                        margin: 0;
+                    "#,
+                    role.css()
+                );
+                Style::new(style_str).expect("Failed to create style for text")
+            })
+            .clone()
+    })
+}
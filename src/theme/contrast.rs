@@ -0,0 +1,39 @@
+//! # Contrast
+//! helps to choose readable text colors `https://www.w3.org/TR/WCAG21/#contrast-minimum`.
+//!
+//! `GButton` uses `on_color` for its label if `background_color` is set and `label_color` is not.
+//! In debug builds `GButton` also warns in the browser console if `label_color` and the container color have contrast ratio below `MIN_CONTRAST_RATIO`.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::theme::{contrast_ratio, on_color};
+//!
+//! let label_color = on_color(&AttrValue::from("#FFD8E4")); // "#000"
+//! let ratio = contrast_ratio("#FFFFFF", "#6750A4"); // Some(6.4..)
+//! ```
+
+use std::str::FromStr;
+
+use color_art::Color;
+use yew::AttrValue;
+
+pub const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// Returns `None` if one of the colors can not be parsed
+pub fn contrast_ratio(foreground: &str, background: &str) -> Option<f64> {
+    let foreground = Color::from_str(foreground).ok()?;
+    let background = Color::from_str(background).ok()?;
+    Some(color_art::contrast_ratio(&foreground, &background))
+}
+
+/// Black or white, whichever has the higher contrast with the `background`
+pub fn on_color(background: &AttrValue) -> AttrValue {
+    let white = contrast_ratio("#FFFFFF", background).unwrap_or(0.0);
+    let black = contrast_ratio("#000000", background).unwrap_or(0.0);
+    if black > white {
+        AttrValue::from("#000")
+    } else {
+        AttrValue::from("#fff")
+    }
+}
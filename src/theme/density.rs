@@ -0,0 +1,73 @@
+//! # GDensity
+//! is the Material density scale `https://m3.material.io/foundations/layout/understanding-layout/spacing#density`.
+//!
+//! Every step down makes controls 4px lower: `GButton` is 40px high by default (36px, 32px and 28px with -1, -2 and -3),
+//! its inline paddings are 4px narrower and its icon offset is 2px smaller,
+//! `GTextInput` is 56px high (52px, 48px and 44px). Paddings, label and icon offsets of `GTextInput` are shrunk together with the height.
+//!
+//! Density can be set for the whole app with `density` of `GTheme` (rendered as `--md-sys-density-scale` custom property)
+//! or for one component with its `density` attribute. An explicit `height` attribute of a component wins over density.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme {
+//!     density: GDensity::Minus2,
+//!     ..GTheme::default()
+//! };
+//!
+//! <GThemeProvider {theme}>
+//!     <GButton id="compact_button" label="Save" />
+//!     <GButton id="default_button" label="Cancel" density={GDensity::Default} />
+//! </GThemeProvider>
+//! ```
+
+use yew::AttrValue;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "i8", into = "i8"))]
+pub enum GDensity {
+    #[default]
+    Default,
+    Minus1,
+    Minus2,
+    Minus3,
+}
+
+impl GDensity {
+    /// 0, -1, -2 or -3
+    pub fn scale(&self) -> i8 {
+        -(*self as i8)
+    }
+}
+
+impl TryFrom<i8> for GDensity {
+    type Error = String;
+
+    fn try_from(scale: i8) -> Result<Self, Self::Error> {
+        match scale {
+            0 => Ok(GDensity::Default),
+            -1 => Ok(GDensity::Minus1),
+            -2 => Ok(GDensity::Minus2),
+            -3 => Ok(GDensity::Minus3),
+            _ => Err(format!("Wrong density scale {scale}, it must be 0, -1, -2 or -3")),
+        }
+    }
+}
+
+impl From<GDensity> for i8 {
+    fn from(density: GDensity) -> Self {
+        density.scale()
+    }
+}
+
+/// `calc({base} + {scale} * {step})`, scale is taken from `--md-sys-density-scale` if `density` is `None`
+pub(crate) fn density_size(base: &str, step: &str, density: Option<GDensity>) -> AttrValue {
+    let scale = match density {
+        Some(density) => density.scale().to_string(),
+        None => String::from("var(--md-sys-density-scale, 0)"),
+    };
+    AttrValue::from(format!("calc({base} + {scale} * {step})"))
+}
@@ -0,0 +1,125 @@
+//! # GElevation
+//! is the Material 3 elevation scale `https://m3.material.io/styles/elevation/tokens`.
+//!
+//! Every level has a shadow and a surface tint. In dark theme shadows are hard to see, so elevated surfaces
+//! are also tinted with the `primary` color (the higher the level, the stronger the tint).
+//!
+//! Levels: 0 (0dp), 1 (1dp), 2 (3dp), 3 (6dp), 4 (8dp), 5 (12dp).
+//!
+//! `GButton` uses Level1 (resting), Level2 (hover) and Level1 (pressed) for `GButtonStyle::Elevated`
+//! and Level0, Level1 and Level0 for `GButtonStyle::Filled`.
+//!
+//! ## Examples
+//! Use the same elevation for your own cards:
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme::default();
+//! let card_style = format!(
+//!     "box-shadow: {}; background-color: {};",
+//!     GElevation::Level1.box_shadow(&theme),
+//!     GElevation::Level1.surface_color(&theme),
+//! );
+//! ```
+
+use std::str::FromStr;
+
+use color_art::Color;
+use yew::AttrValue;
+
+use super::GTheme;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, PartialOrd, Eq, Ord, Hash)]
+pub enum GElevation {
+    #[default]
+    Level0,
+    Level1,
+    Level2,
+    Level3,
+    Level4,
+    Level5,
+}
+
+impl GElevation {
+    pub fn level(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn dp(&self) -> u8 {
+        match self {
+            GElevation::Level0 => 0,
+            GElevation::Level1 => 1,
+            GElevation::Level2 => 3,
+            GElevation::Level3 => 6,
+            GElevation::Level4 => 8,
+            GElevation::Level5 => 12,
+        }
+    }
+
+    pub fn surface_tint_opacity(&self) -> f64 {
+        match self {
+            GElevation::Level0 => 0.0,
+            GElevation::Level1 => 0.05,
+            GElevation::Level2 => 0.08,
+            GElevation::Level3 => 0.11,
+            GElevation::Level4 => 0.12,
+            GElevation::Level5 => 0.14,
+        }
+    }
+
+    /// Value for css `box-shadow` property
+    pub fn box_shadow(&self, theme: &GTheme) -> AttrValue {
+        let shadow = &theme.colors().shadow;
+        // `transparent`, `var(...)` or `currentColor` can not be parsed, the browser mixes them
+        let (key, ambient) = match Color::from_str(shadow) {
+            Ok(color) => (color.fade(0.3).rgba(), color.fade(0.15).rgba()),
+            Err(_) => (
+                format!("color-mix(in srgb, {shadow} 30%, transparent)"),
+                format!("color-mix(in srgb, {shadow} 15%, transparent)"),
+            ),
+        };
+        let shadows = match self {
+            GElevation::Level0 => return AttrValue::from("none"),
+            GElevation::Level1 => format!("0 1px 2px 0 {key}, 0 1px 3px 1px {ambient}"),
+            GElevation::Level2 => format!("0 1px 2px 0 {key}, 0 2px 6px 2px {ambient}"),
+            GElevation::Level3 => format!("0 1px 3px 0 {key}, 0 4px 8px 3px {ambient}"),
+            GElevation::Level4 => format!("0 2px 3px 0 {key}, 0 6px 10px 4px {ambient}"),
+            GElevation::Level5 => format!("0 4px 4px 0 {key}, 0 8px 12px 6px {ambient}"),
+        };
+        AttrValue::from(shadows)
+    }
+
+    /// `box-shadow` value which follows `--md-sys-elevation-level*` token of `GThemeProvider`
+    pub fn box_shadow_var(&self) -> AttrValue {
+        AttrValue::from(format!("var(--md-sys-elevation-level{}, {})", self.level(), self.box_shadow(&GTheme::default())))
+    }
+
+    /// Css color of `color` with the surface tint of `GThemeProvider` (the tint is transparent in light mode)
+    pub fn tint_var(&self, color: &str) -> AttrValue {
+        AttrValue::from(format!(
+            "color-mix(in srgb, var(--md-sys-color-surface-tint, transparent) var(--md-sys-elevation-tint-level{}, 0%), {color})",
+            self.level()
+        ))
+    }
+
+    /// `surface` color of the theme with `primary` tint overlay in dark mode
+    pub fn surface_color(&self, theme: &GTheme) -> AttrValue {
+        self.tint(&theme.colors().surface, theme)
+    }
+
+    /// Adds `primary` tint overlay to the `color` in dark mode
+    pub fn tint(&self, color: &AttrValue, theme: &GTheme) -> AttrValue {
+        if !theme.is_dark() || *self == GElevation::Level0 {
+            return color.clone();
+        }
+        let tint = &theme.colors().primary;
+        match (Color::from_str(color), Color::from_str(tint)) {
+            (Ok(color), Ok(tint)) => AttrValue::from(color.mix_with(&tint, self.surface_tint_opacity()).hex()),
+            _ => AttrValue::from(format!(
+                "color-mix(in srgb, {tint} {}%, {color})",
+                (self.surface_tint_opacity() * 100.0).round()
+            )),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! # Theme json
+//! is available with `serde` feature.
+//!
+//! `GTheme::from_json` accepts both the export of Material Theme Builder `https://material-foundation.github.io/material-theme-builder/`
+//! (only `light` and `dark` schemes are used, other keys are ignored) and json of `GTheme` itself.
+//! Missing color roles get Material 3 baseline values. Json which is neither of them (e.g. an unknown key of `GTheme`) is an error.
+//!
+//! ## Examples
+//! ```
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme::from_json(r##"{
+//!     "seed": "#00696D",
+//!     "schemes": {
+//!         "light": { "primary": "#00696D", "onPrimary": "#FFFFFF" },
+//!         "dark": { "primary": "#4CDADF", "onPrimary": "#003739" }
+//!     }
+//! }"##).expect("Wrong theme json");
+//!
+//! let json = theme.to_json().unwrap();
+//! let theme_builder_json = theme.to_theme_builder_json().unwrap();
+//! ```
+
+use serde::{Deserialize, Serialize};
+use yew::AttrValue;
+
+use super::{GColorRoles, GTheme};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GThemeBuilderSchemes {
+    pub light: GColorRoles,
+    pub dark: GColorRoles,
+}
+
+impl Default for GThemeBuilderSchemes {
+    fn default() -> Self {
+        Self {
+            light: GColorRoles::default(),
+            dark: GColorRoles::dark(),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GThemeBuilderExport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<AttrValue>,
+    pub schemes: GThemeBuilderSchemes,
+}
+
+impl From<GThemeBuilderExport> for GTheme {
+    fn from(export: GThemeBuilderExport) -> Self {
+        Self {
+            light: export.schemes.light,
+            dark: export.schemes.dark,
+            ..GTheme::default()
+        }
+    }
+}
+
+impl From<&GTheme> for GThemeBuilderExport {
+    fn from(theme: &GTheme) -> Self {
+        Self {
+            seed: None,
+            schemes: GThemeBuilderSchemes {
+                light: theme.light.clone(),
+                dark: theme.dark.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GThemeJson {
+    ThemeBuilder(GThemeBuilderExport),
+    Theme(GTheme),
+}
+
+impl GTheme {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        // structs also deserialize from arrays, a theme is always an object
+        let json = serde_json::Value::Object(serde_json::from_str(json)?);
+        Ok(match serde_json::from_value::<GThemeJson>(json)? {
+            GThemeJson::ThemeBuilder(export) => GTheme::from(export),
+            GThemeJson::Theme(theme) => theme,
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    pub fn to_theme_builder_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&GThemeBuilderExport::from(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{GTypescale, GTypescaleStyle};
+
+    #[test]
+    fn theme_builder_export() {
+        let theme = GTheme::from_json(r##"{ "seed": "#00696D", "schemes": { "light": { "primary": "#00696D" } } }"##).unwrap();
+        assert_eq!(theme.light.primary, "#00696D");
+        assert_eq!(theme.dark, GColorRoles::dark());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let theme = GTheme::from_seed("#6750A4").unwrap();
+        assert_eq!(GTheme::from_json(&theme.to_json().unwrap()).unwrap(), theme);
+        assert_eq!(GTheme::from_json(&theme.to_theme_builder_json().unwrap()).unwrap().light, theme.light);
+    }
+
+    #[test]
+    fn typescale_roles_from_json() {
+        let theme = GTheme::from_json(r#"{"typography":{"bodyLarge":{"size":"18px","lineHeight":"26px","weight":"400","tracking":"0px"}}}"#).unwrap();
+        assert_eq!(theme.typography.body_large.size, "18px");
+        assert_eq!(theme.typography.title_large, GTypescaleStyle::baseline(GTypescale::TitleLarge));
+        assert!(theme.css_variables().contains("--md-sys-typescale-body-large-size: 18px;"));
+    }
+
+    #[test]
+    fn unknown_json_is_rejected() {
+        assert!(GTheme::from_json(r#"{"foo":1}"#).is_err());
+        assert!(GTheme::from_json(r#"{"lihgt":{}}"#).is_err());
+        assert!(GTheme::from_json("[]").is_err());
+    }
+}
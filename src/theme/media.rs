@@ -0,0 +1,11 @@
+//! # Media queries
+//! of user preferences which components follow in their css.
+//!
+//! `REDUCED_MOTION_MEDIA` turns transitions off (see `motion` module).
+//!
+//! In forced colors mode (e.g. Windows high contrast) theme colors, tints and shadows are replaced by the system colors.
+//! `GButton`, `GTextInput` and `GIcon` then show borders in `ButtonText`, hover and focus in `Highlight` and disabled state in `GrayText`.
+
+pub(crate) const REDUCED_MOTION_MEDIA: &str = "@media (prefers-reduced-motion: reduce)";
+
+pub(crate) const FORCED_COLORS_MEDIA: &str = "@media (forced-colors: active)";
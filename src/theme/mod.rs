@@ -0,0 +1,471 @@
+//! # GThemeProvider
+//! shares one `GTheme` with every `GButton`, `GTextInput` and `GIcon` inside it.
+//!
+//! `GTheme` holds the Material 3 color roles `https://m3.material.io/styles/color/roles`.
+//! Components read their default colors from the nearest provider, so you can restyle the whole app in one place.
+//! Colors passed via attributes (e.g. `background_color` of `GButton`) still win over the theme.
+//!
+//! `GTheme` has `light` and `dark` color roles and `mode` which chooses one of them. The mode can be switched at runtime
+//! from any component inside the provider with `use_theme_switcher()`, without passing new attributes to components.
+//! `GThemeMode::System` follows `prefers-color-scheme` of the OS and updates all components when the OS setting changes.
+//!
+//! Theme roles used by components:
+//! - `GButton`: `primary` (background), `surface` (background of Elevated), `on_primary` (label of Filled), `primary` (label of Elevated, Outlined and Text), `outline`, `on_surface` (disabled state), `shadow`
+//! - `GTextInput`: `outline`, `on_surface`, `primary`, `surface`, `surface_variant` (Filled style), `on_surface_variant`
+//! - `GIcon`: `on_surface_variant`
+//!
+//! Without a provider components use Material 3 baseline colors and `GIcon` inherits the text color.
+//!
+//! The provider also renders the theme as css custom properties on a `display: contents` wrapper around its children,
+//! or on `:root` if `root` attribute is `true`. Components use these variables, so switching the theme updates only the provider:
+//! - `--md-sys-color-*`: color roles, e.g. `--md-sys-color-primary`, `--md-sys-color-on-surface-variant`
+//! - `--md-sys-elevation-level0` ... `--md-sys-elevation-level5`: box shadows
+//! - `--md-sys-elevation-tint-level0` ... `--md-sys-elevation-tint-level5`: surface tint opacity (`0%` in light mode)
+//! - `--md-sys-shape-corner-*`: corner radii, e.g. `--md-sys-shape-corner-medium` (see `GShape`)
+//! - `--md-sys-typescale-*`: type scale, e.g. `--md-sys-typescale-body-large-size` (see `GTypescale`)
+//! - `--md-sys-motion-easing-*`, `--md-sys-motion-duration-*`: motion tokens, e.g. `--md-sys-motion-duration-short4` (see `GDuration` and `GEasing`)
+//! - `--md-sys-density-scale`: 0, -1, -2 or -3 (see `GDensity`)
+//! - `--md-sys-direction-sign`: 1 or -1 if `direction` of `GTheme` is `GDirection::Ltr` or `GDirection::Rtl`, mirrors cut corners (see `GShape`)
+//! - `--md-ref-typeface-brand`, `--md-ref-typeface-plain`: font families of `GTypography`
+//!
+//! Components place leading and trailing content with logical css properties, so they follow the text direction:
+//! in right-to-left layout leading icons, labels and supporting text are on the right. The direction comes from the `dir`
+//! attribute of the page or can be set with `direction` of `GTheme` (`GDirection::Rtl`).
+//!
+//! Plain html can use the same tokens, e.g. `style="color: var(--md-sys-color-on-surface)"`. Use `GTheme::css_variables` to get them as a string.
+//!
+//! Providers can be nested. A nested provider without `theme` attribute takes the theme of the parent provider
+//! and changes only the tokens from its `overrides` attribute (see `GThemeOverrides`), e.g. a dark toolbar or a "danger zone" with error-colored buttons.
+//! Every provider has its own mode, `use_theme_switcher()` switches the mode of the nearest one.
+//!
+//! With `serde` feature themes can be loaded from json exported by Material Theme Builder or saved to json (see `GTheme::from_json`).
+//!
+//! To generate all color roles from one brand color use `GTheme::from_seed` or `GColorScheme::from_seed` (see `GColorScheme`).
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme {
+//!     light: GColorRoles {
+//!         primary: AttrValue::from("#006A6A"),
+//!         ..GColorRoles::default()
+//!     },
+//!     ..GTheme::default()
+//! };
+//!
+//! <GThemeProvider {theme}>
+//!     <GButton id="themed_button" label="Button" />
+//! </GThemeProvider>
+//! ```
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme::from_seed("#00696D").expect("Wrong seed color");
+//!
+//! <GThemeProvider {theme}>
+//!     <GButton id="themed_button" label="Button" />
+//! </GThemeProvider>
+//! ```
+//!
+//! Switch to dark theme from any component inside `GThemeProvider`:
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! #[function_component(DarkModeButton)]
+//! fn dark_mode_button() -> Html {
+//!     let switcher = use_theme_switcher().expect("No GThemeProvider found");
+//!     let onclick = Callback::from(move |_| switcher.toggle());
+//!     html! {
+//!         <GButton id="dark_mode_button" has_icon="dark_mode" icon_style={GIconStyle::Outlined} {onclick} />
+//!     }
+//! }
+//! ```
+
+use gloo_events::EventListener;
+use stylist::yew::Global;
+use web_sys::MediaQueryList;
+use yew::prelude::*;
+
+pub use contrast::{contrast_ratio, on_color, MIN_CONTRAST_RATIO};
+pub(crate) use density::density_size;
+pub use density::GDensity;
+pub use elevation::GElevation;
+pub(crate) use media::{FORCED_COLORS_MEDIA, REDUCED_MOTION_MEDIA};
+pub use motion::{prefers_reduced_motion, GDuration, GEasing};
+pub use scheme::{GColorScheme, GCorePalettes, GTonalPalette};
+pub use shape::{GCornerFamily, GShape, GShapeCorner, GShapeScale};
+#[cfg(feature = "serde")]
+pub use json::{GThemeBuilderExport, GThemeBuilderSchemes};
+pub use overrides::{GColorRole, GColorValue, GThemeOverrides};
+pub use state_layer::{GState, GStateLayer};
+pub(crate) use tokens::sys_color;
+pub use typography::{GTypescale, GTypescaleStyle, GTypography};
+
+mod contrast;
+mod density;
+mod elevation;
+mod media;
+mod motion;
+#[cfg(feature = "serde")]
+mod json;
+mod overrides;
+mod scheme;
+mod shape;
+mod state_layer;
+mod tokens;
+mod typography;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "camelCase", default))]
+pub struct GColorRoles {
+    pub primary: AttrValue,
+    pub on_primary: AttrValue,
+    pub primary_container: AttrValue,
+    pub on_primary_container: AttrValue,
+    pub secondary: AttrValue,
+    pub on_secondary: AttrValue,
+    pub secondary_container: AttrValue,
+    pub on_secondary_container: AttrValue,
+    pub tertiary: AttrValue,
+    pub on_tertiary: AttrValue,
+    pub tertiary_container: AttrValue,
+    pub on_tertiary_container: AttrValue,
+    pub error: AttrValue,
+    pub on_error: AttrValue,
+    pub error_container: AttrValue,
+    pub on_error_container: AttrValue,
+    pub background: AttrValue,
+    pub on_background: AttrValue,
+    pub surface: AttrValue,
+    pub on_surface: AttrValue,
+    pub surface_variant: AttrValue,
+    pub on_surface_variant: AttrValue,
+    pub outline: AttrValue,
+    pub outline_variant: AttrValue,
+    pub shadow: AttrValue,
+    pub scrim: AttrValue,
+    pub inverse_surface: AttrValue,
+    pub inverse_on_surface: AttrValue,
+    pub inverse_primary: AttrValue,
+}
+
+impl Default for GColorRoles {
+    fn default() -> Self {
+        Self {
+            primary: AttrValue::from("#6750A4"),
+            on_primary: AttrValue::from("#FFFFFF"),
+            primary_container: AttrValue::from("#EADDFF"),
+            on_primary_container: AttrValue::from("#21005D"),
+            secondary: AttrValue::from("#625B71"),
+            on_secondary: AttrValue::from("#FFFFFF"),
+            secondary_container: AttrValue::from("#E8DEF8"),
+            on_secondary_container: AttrValue::from("#1D192B"),
+            tertiary: AttrValue::from("#7D5260"),
+            on_tertiary: AttrValue::from("#FFFFFF"),
+            tertiary_container: AttrValue::from("#FFD8E4"),
+            on_tertiary_container: AttrValue::from("#31111D"),
+            error: AttrValue::from("#B3261E"),
+            on_error: AttrValue::from("#FFFFFF"),
+            error_container: AttrValue::from("#F9DEDC"),
+            on_error_container: AttrValue::from("#410E0B"),
+            background: AttrValue::from("#FFFBFE"),
+            on_background: AttrValue::from("#1C1B1F"),
+            surface: AttrValue::from("#FFFBFE"),
+            on_surface: AttrValue::from("#1C1B1F"),
+            surface_variant: AttrValue::from("#E7E0EC"),
+            on_surface_variant: AttrValue::from("#49454F"),
+            outline: AttrValue::from("#79747E"),
+            outline_variant: AttrValue::from("#CAC4D0"),
+            shadow: AttrValue::from("#000000"),
+            scrim: AttrValue::from("#000000"),
+            inverse_surface: AttrValue::from("#313033"),
+            inverse_on_surface: AttrValue::from("#F4EFF4"),
+            inverse_primary: AttrValue::from("#D0BCFF"),
+        }
+    }
+}
+
+impl GColorRoles {
+    /// Role names in kebab-case (as in `--md-sys-color-*` tokens) with their colors
+    pub fn roles(&self) -> [(&'static str, &AttrValue); 29] {
+        [
+            ("primary", &self.primary),
+            ("on-primary", &self.on_primary),
+            ("primary-container", &self.primary_container),
+            ("on-primary-container", &self.on_primary_container),
+            ("secondary", &self.secondary),
+            ("on-secondary", &self.on_secondary),
+            ("secondary-container", &self.secondary_container),
+            ("on-secondary-container", &self.on_secondary_container),
+            ("tertiary", &self.tertiary),
+            ("on-tertiary", &self.on_tertiary),
+            ("tertiary-container", &self.tertiary_container),
+            ("on-tertiary-container", &self.on_tertiary_container),
+            ("error", &self.error),
+            ("on-error", &self.on_error),
+            ("error-container", &self.error_container),
+            ("on-error-container", &self.on_error_container),
+            ("background", &self.background),
+            ("on-background", &self.on_background),
+            ("surface", &self.surface),
+            ("on-surface", &self.on_surface),
+            ("surface-variant", &self.surface_variant),
+            ("on-surface-variant", &self.on_surface_variant),
+            ("outline", &self.outline),
+            ("outline-variant", &self.outline_variant),
+            ("shadow", &self.shadow),
+            ("scrim", &self.scrim),
+            ("inverse-surface", &self.inverse_surface),
+            ("inverse-on-surface", &self.inverse_on_surface),
+            ("inverse-primary", &self.inverse_primary),
+        ]
+    }
+
+    pub fn role(&self, name: &str) -> Option<&AttrValue> {
+        self.roles().into_iter().find(|(role, _)| *role == name).map(|(_, value)| value)
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            primary: AttrValue::from("#D0BCFF"),
+            on_primary: AttrValue::from("#381E72"),
+            primary_container: AttrValue::from("#4F378B"),
+            on_primary_container: AttrValue::from("#EADDFF"),
+            secondary: AttrValue::from("#CCC2DC"),
+            on_secondary: AttrValue::from("#332D41"),
+            secondary_container: AttrValue::from("#4A4458"),
+            on_secondary_container: AttrValue::from("#E8DEF8"),
+            tertiary: AttrValue::from("#EFB8C8"),
+            on_tertiary: AttrValue::from("#492532"),
+            tertiary_container: AttrValue::from("#633B48"),
+            on_tertiary_container: AttrValue::from("#FFD8E4"),
+            error: AttrValue::from("#F2B8B5"),
+            on_error: AttrValue::from("#601410"),
+            error_container: AttrValue::from("#8C1D18"),
+            on_error_container: AttrValue::from("#F9DEDC"),
+            background: AttrValue::from("#1C1B1F"),
+            on_background: AttrValue::from("#E6E1E5"),
+            surface: AttrValue::from("#1C1B1F"),
+            on_surface: AttrValue::from("#E6E1E5"),
+            surface_variant: AttrValue::from("#49454F"),
+            on_surface_variant: AttrValue::from("#CAC4D0"),
+            outline: AttrValue::from("#938F99"),
+            outline_variant: AttrValue::from("#49454F"),
+            shadow: AttrValue::from("#000000"),
+            scrim: AttrValue::from("#000000"),
+            inverse_surface: AttrValue::from("#E6E1E5"),
+            inverse_on_surface: AttrValue::from("#313033"),
+            inverse_primary: AttrValue::from("#6750A4"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
+pub enum GThemeMode {
+    #[default]
+    Light,
+    Dark,
+    System,
+}
+
+/// Text direction of components inside `GThemeProvider`. `Auto` inherits it from the `dir` attribute of the page
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
+pub enum GDirection {
+    #[default]
+    Auto,
+    Ltr,
+    Rtl,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default, deny_unknown_fields))]
+pub struct GTheme {
+    pub light: GColorRoles,
+    pub dark: GColorRoles,
+    pub mode: GThemeMode,
+    pub typography: GTypography,
+    pub shape: GShapeScale,
+    /// Instant transitions and no ripple, even if the OS has no `prefers-reduced-motion` setting
+    pub reduce_motion: bool,
+    pub density: GDensity,
+    pub direction: GDirection,
+}
+
+impl Default for GTheme {
+    fn default() -> Self {
+        Self {
+            light: GColorRoles::default(),
+            dark: GColorRoles::dark(),
+            mode: GThemeMode::default(),
+            typography: GTypography::default(),
+            shape: GShapeScale::default(),
+            reduce_motion: false,
+            density: GDensity::default(),
+            direction: GDirection::default(),
+        }
+    }
+}
+
+impl GTheme {
+    pub fn from_seed(seed: &str) -> Result<Self, color_art::Error> {
+        let scheme = GColorScheme::from_seed(seed)?;
+        Ok(Self {
+            light: scheme.light,
+            dark: scheme.dark,
+            mode: GThemeMode::default(),
+            typography: GTypography::default(),
+            shape: GShapeScale::default(),
+            reduce_motion: false,
+            density: GDensity::default(),
+            direction: GDirection::default(),
+        })
+    }
+
+    pub fn colors(&self) -> &GColorRoles {
+        if self.is_dark() {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+
+    pub fn is_dark(&self) -> bool {
+        match self.mode {
+            GThemeMode::Light => false,
+            GThemeMode::Dark => true,
+            GThemeMode::System => prefers_dark_color_scheme(),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct GThemeSwitcher {
+    pub mode: GThemeMode,
+    set_mode: UseStateSetter<GThemeMode>,
+}
+
+impl GThemeSwitcher {
+    pub fn set(&self, mode: GThemeMode) {
+        self.set_mode.set(mode)
+    }
+
+    pub fn toggle(&self) {
+        let is_dark = match self.mode {
+            GThemeMode::Light => false,
+            GThemeMode::Dark => true,
+            GThemeMode::System => prefers_dark_color_scheme(),
+        };
+        if is_dark {
+            self.set(GThemeMode::Light)
+        } else {
+            self.set(GThemeMode::Dark)
+        }
+    }
+}
+
+#[hook]
+pub fn use_theme_switcher() -> Option<GThemeSwitcher> {
+    use_context::<GThemeSwitcher>()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GThemeProviderProps {
+    #[prop_or_default]
+    pub theme: Option<GTheme>,
+    #[prop_or_default]
+    pub overrides: GThemeOverrides,
+    #[prop_or_default]
+    pub root: bool,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(GThemeProvider)]
+pub fn theme_provider(props: &GThemeProviderProps) -> Html {
+    let parent_theme = use_context::<GTheme>();
+    let base_theme = props.theme.clone().or(parent_theme).unwrap_or_default();
+    let base_theme = props.overrides.apply(&base_theme);
+    let mode = use_state_eq(|| base_theme.mode);
+    {
+        let mode = mode.clone();
+        use_effect_with(base_theme.mode, move |theme_mode| mode.set(*theme_mode));
+    }
+    let system_dark = use_state_eq(prefers_dark_color_scheme);
+    {
+        let system_dark = system_dark.clone();
+        use_effect_with(*mode, move |mode| {
+            let listener = match (mode, color_scheme_media_query()) {
+                (GThemeMode::System, Some(query)) => {
+                    system_dark.set(query.matches());
+                    let target = query.clone();
+                    Some(EventListener::new(&query, "change", move |_| system_dark.set(target.matches())))
+                },
+                _ => None,
+            };
+            move || drop(listener)
+        });
+    }
+    let resolved_mode = match *mode {
+        GThemeMode::System if *system_dark => GThemeMode::Dark,
+        GThemeMode::System => GThemeMode::Light,
+        mode => mode,
+    };
+    let theme = GTheme {
+        mode: resolved_mode,
+        ..base_theme
+    };
+    let switcher = GThemeSwitcher {
+        mode: *mode,
+        set_mode: mode.setter(),
+    };
+    use_effect_with(theme.typography.clone(), |typography| {
+        if typography.brand.contains("Roboto") || typography.plain.contains("Roboto") {
+            crate::fonts::inject_roboto();
+        }
+    });
+    let css_variables = use_memo(theme.clone(), |theme| {
+        let color_scheme = if theme.is_dark() { "dark" } else { "light" };
+        let direction = match theme.direction {
+            GDirection::Auto => "",
+            GDirection::Ltr => "direction: ltr; --md-sys-direction-sign: 1; ",
+            GDirection::Rtl => "direction: rtl; --md-sys-direction-sign: -1; ",
+        };
+        format!("color-scheme: {color_scheme}; {direction}{}", theme.css_variables())
+    });
+    let tokens = if props.root {
+        html! {
+            <>
+                <Global css={(*css_variables).clone()} />
+                {props.children.clone()}
+            </>
+        }
+    } else {
+        html! {
+            <div class="g_theme" style={format!("display: contents; {css_variables}")}>
+                {props.children.clone()}
+            </div>
+        }
+    };
+    html! {
+        <ContextProvider<GThemeSwitcher> context={switcher}>
+            <ContextProvider<GTheme> context={theme}>
+                {tokens}
+            </ContextProvider<GTheme>>
+        </ContextProvider<GThemeSwitcher>>
+    }
+}
+
+fn color_scheme_media_query() -> Option<MediaQueryList> {
+    web_sys::window()?.match_media("(prefers-color-scheme: dark)").ok()?
+}
+
+pub fn prefers_dark_color_scheme() -> bool {
+    color_scheme_media_query().map(|query| query.matches()).unwrap_or(false)
+}
@@ -0,0 +1,191 @@
+//! # Motion
+//! Material 3 easing and duration tokens `https://m3.material.io/styles/motion/easing-and-duration/tokens-specs`.
+//!
+//! `GThemeProvider` renders them as `--md-sys-motion-easing-*` and `--md-sys-motion-duration-*` custom properties.
+//! Components use:
+//! - `GButton`: `Medium2` with `Standard` easing (shadow), `Long4` (ripple)
+//! - `GTextInput`: `Short4` with `Standard` easing (border and label)
+//!
+//! If the OS has `prefers-reduced-motion: reduce` or `reduce_motion` of `GTheme` is `true`, transitions are instant
+//! and `GButton` shows no ripple. With `reduce_motion` all duration tokens are `0ms`.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme {
+//!     reduce_motion: true,
+//!     ..GTheme::default()
+//! };
+//! let card_style = format!(
+//!     "transition: transform {} {};",
+//!     GDuration::Medium4.var(),
+//!     GEasing::EmphasizedDecelerate.var(),
+//! );
+//! ```
+
+use yew::AttrValue;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GEasing {
+    #[default]
+    Standard,
+    StandardAccelerate,
+    StandardDecelerate,
+    Emphasized,
+    EmphasizedAccelerate,
+    EmphasizedDecelerate,
+    Legacy,
+    LegacyAccelerate,
+    LegacyDecelerate,
+    Linear,
+}
+
+impl GEasing {
+    pub const ALL: [GEasing; 10] = [
+        GEasing::Standard,
+        GEasing::StandardAccelerate,
+        GEasing::StandardDecelerate,
+        GEasing::Emphasized,
+        GEasing::EmphasizedAccelerate,
+        GEasing::EmphasizedDecelerate,
+        GEasing::Legacy,
+        GEasing::LegacyAccelerate,
+        GEasing::LegacyDecelerate,
+        GEasing::Linear,
+    ];
+
+    /// Name in kebab-case, as in `--md-sys-motion-easing-*` tokens
+    pub fn name(&self) -> &'static str {
+        match self {
+            GEasing::Standard => "standard",
+            GEasing::StandardAccelerate => "standard-accelerate",
+            GEasing::StandardDecelerate => "standard-decelerate",
+            GEasing::Emphasized => "emphasized",
+            GEasing::EmphasizedAccelerate => "emphasized-accelerate",
+            GEasing::EmphasizedDecelerate => "emphasized-decelerate",
+            GEasing::Legacy => "legacy",
+            GEasing::LegacyAccelerate => "legacy-accelerate",
+            GEasing::LegacyDecelerate => "legacy-decelerate",
+            GEasing::Linear => "linear",
+        }
+    }
+
+    /// Value for css `transition-timing-function` and `animation-timing-function` properties
+    pub fn cubic_bezier(&self) -> &'static str {
+        match self {
+            GEasing::Standard => "cubic-bezier(0.2, 0, 0, 1)",
+            GEasing::StandardAccelerate => "cubic-bezier(0.3, 0, 1, 1)",
+            GEasing::StandardDecelerate => "cubic-bezier(0, 0, 0, 1)",
+            GEasing::Emphasized => "cubic-bezier(0.2, 0, 0, 1)",
+            GEasing::EmphasizedAccelerate => "cubic-bezier(0.3, 0, 0.8, 0.15)",
+            GEasing::EmphasizedDecelerate => "cubic-bezier(0.05, 0.7, 0.1, 1)",
+            GEasing::Legacy => "cubic-bezier(0.4, 0, 0.2, 1)",
+            GEasing::LegacyAccelerate => "cubic-bezier(0.4, 0, 1, 1)",
+            GEasing::LegacyDecelerate => "cubic-bezier(0, 0, 0.2, 1)",
+            GEasing::Linear => "cubic-bezier(0, 0, 1, 1)",
+        }
+    }
+
+    /// `var(--md-sys-motion-easing-{name}, {cubic-bezier})`
+    pub fn var(&self) -> AttrValue {
+        AttrValue::from(format!("var(--md-sys-motion-easing-{}, {})", self.name(), self.cubic_bezier()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GDuration {
+    Short1,
+    Short2,
+    Short3,
+    Short4,
+    Medium1,
+    Medium2,
+    Medium3,
+    Medium4,
+    Long1,
+    Long2,
+    Long3,
+    Long4,
+    ExtraLong1,
+    ExtraLong2,
+    ExtraLong3,
+    ExtraLong4,
+}
+
+impl GDuration {
+    pub const ALL: [GDuration; 16] = [
+        GDuration::Short1,
+        GDuration::Short2,
+        GDuration::Short3,
+        GDuration::Short4,
+        GDuration::Medium1,
+        GDuration::Medium2,
+        GDuration::Medium3,
+        GDuration::Medium4,
+        GDuration::Long1,
+        GDuration::Long2,
+        GDuration::Long3,
+        GDuration::Long4,
+        GDuration::ExtraLong1,
+        GDuration::ExtraLong2,
+        GDuration::ExtraLong3,
+        GDuration::ExtraLong4,
+    ];
+
+    /// Name in kebab-case, as in `--md-sys-motion-duration-*` tokens
+    pub fn name(&self) -> &'static str {
+        match self {
+            GDuration::Short1 => "short1",
+            GDuration::Short2 => "short2",
+            GDuration::Short3 => "short3",
+            GDuration::Short4 => "short4",
+            GDuration::Medium1 => "medium1",
+            GDuration::Medium2 => "medium2",
+            GDuration::Medium3 => "medium3",
+            GDuration::Medium4 => "medium4",
+            GDuration::Long1 => "long1",
+            GDuration::Long2 => "long2",
+            GDuration::Long3 => "long3",
+            GDuration::Long4 => "long4",
+            GDuration::ExtraLong1 => "extra-long1",
+            GDuration::ExtraLong2 => "extra-long2",
+            GDuration::ExtraLong3 => "extra-long3",
+            GDuration::ExtraLong4 => "extra-long4",
+        }
+    }
+
+    pub fn millis(&self) -> u32 {
+        match self {
+            GDuration::Short1 => 50,
+            GDuration::Short2 => 100,
+            GDuration::Short3 => 150,
+            GDuration::Short4 => 200,
+            GDuration::Medium1 => 250,
+            GDuration::Medium2 => 300,
+            GDuration::Medium3 => 350,
+            GDuration::Medium4 => 400,
+            GDuration::Long1 => 450,
+            GDuration::Long2 => 500,
+            GDuration::Long3 => 550,
+            GDuration::Long4 => 600,
+            GDuration::ExtraLong1 => 700,
+            GDuration::ExtraLong2 => 800,
+            GDuration::ExtraLong3 => 900,
+            GDuration::ExtraLong4 => 1000,
+        }
+    }
+
+    /// `var(--md-sys-motion-duration-{name}, {millis}ms)`
+    pub fn var(&self) -> AttrValue {
+        AttrValue::from(format!("var(--md-sys-motion-duration-{}, {}ms)", self.name(), self.millis()))
+    }
+}
+
+pub fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media("(prefers-reduced-motion: reduce)").ok().flatten())
+        .map(|query| query.matches())
+        .unwrap_or(false)
+}
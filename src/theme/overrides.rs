@@ -0,0 +1,172 @@
+//! # GThemeOverrides
+//! changes a part of the theme for a subtree.
+//!
+//! A `GThemeProvider` inside another provider takes the theme of the parent (if its own `theme` attribute is not set)
+//! and applies `overrides` to it. Components inside use the nearest provider, so a section can get its own colors
+//! without passing `background_color`, `border_focus_color`, etc. to every component.
+//!
+//! A color role can be overridden with another role of the same theme (`GColorRole::Error`) or with any css color (`"#B3261E"`).
+//! Role overrides are resolved for light and dark mode separately.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let danger_zone = GThemeOverrides::default()
+//!     .color(GColorRole::Primary, GColorRole::Error)
+//!     .color(GColorRole::OnPrimary, GColorRole::OnError);
+//! let toolbar = GThemeOverrides::default().mode(GThemeMode::Dark);
+//!
+//! <GThemeProvider>
+//!     <GThemeProvider overrides={toolbar}>
+//!         <GButton id="toolbar_button" label="Menu" />
+//!     </GThemeProvider>
+//!     <GThemeProvider overrides={danger_zone}>
+//!         <GButton id="delete_button" label="Delete" />
+//!     </GThemeProvider>
+//! </GThemeProvider>
+//! ```
+
+use yew::AttrValue;
+
+use super::{GColorRoles, GTheme, GThemeMode};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GColorRole {
+    Primary,
+    OnPrimary,
+    PrimaryContainer,
+    OnPrimaryContainer,
+    Secondary,
+    OnSecondary,
+    SecondaryContainer,
+    OnSecondaryContainer,
+    Tertiary,
+    OnTertiary,
+    TertiaryContainer,
+    OnTertiaryContainer,
+    Error,
+    OnError,
+    ErrorContainer,
+    OnErrorContainer,
+    Background,
+    OnBackground,
+    Surface,
+    OnSurface,
+    SurfaceVariant,
+    OnSurfaceVariant,
+    Outline,
+    OutlineVariant,
+    Shadow,
+    Scrim,
+    InverseSurface,
+    InverseOnSurface,
+    InversePrimary,
+}
+
+impl GColorRole {
+    /// Name in kebab-case, as in `--md-sys-color-*` tokens
+    pub fn name(&self) -> &'static str {
+        GColorRoles::default().roles()[*self as usize].0
+    }
+}
+
+impl GColorRoles {
+    pub fn get(&self, role: GColorRole) -> &AttrValue {
+        self.roles()[role as usize].1
+    }
+
+    pub fn get_mut(&mut self, role: GColorRole) -> &mut AttrValue {
+        match role {
+            GColorRole::Primary => &mut self.primary,
+            GColorRole::OnPrimary => &mut self.on_primary,
+            GColorRole::PrimaryContainer => &mut self.primary_container,
+            GColorRole::OnPrimaryContainer => &mut self.on_primary_container,
+            GColorRole::Secondary => &mut self.secondary,
+            GColorRole::OnSecondary => &mut self.on_secondary,
+            GColorRole::SecondaryContainer => &mut self.secondary_container,
+            GColorRole::OnSecondaryContainer => &mut self.on_secondary_container,
+            GColorRole::Tertiary => &mut self.tertiary,
+            GColorRole::OnTertiary => &mut self.on_tertiary,
+            GColorRole::TertiaryContainer => &mut self.tertiary_container,
+            GColorRole::OnTertiaryContainer => &mut self.on_tertiary_container,
+            GColorRole::Error => &mut self.error,
+            GColorRole::OnError => &mut self.on_error,
+            GColorRole::ErrorContainer => &mut self.error_container,
+            GColorRole::OnErrorContainer => &mut self.on_error_container,
+            GColorRole::Background => &mut self.background,
+            GColorRole::OnBackground => &mut self.on_background,
+            GColorRole::Surface => &mut self.surface,
+            GColorRole::OnSurface => &mut self.on_surface,
+            GColorRole::SurfaceVariant => &mut self.surface_variant,
+            GColorRole::OnSurfaceVariant => &mut self.on_surface_variant,
+            GColorRole::Outline => &mut self.outline,
+            GColorRole::OutlineVariant => &mut self.outline_variant,
+            GColorRole::Shadow => &mut self.shadow,
+            GColorRole::Scrim => &mut self.scrim,
+            GColorRole::InverseSurface => &mut self.inverse_surface,
+            GColorRole::InverseOnSurface => &mut self.inverse_on_surface,
+            GColorRole::InversePrimary => &mut self.inverse_primary,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GColorValue {
+    Role(GColorRole),
+    Color(AttrValue),
+}
+
+impl From<GColorRole> for GColorValue {
+    fn from(role: GColorRole) -> Self {
+        GColorValue::Role(role)
+    }
+}
+
+impl From<&'static str> for GColorValue {
+    fn from(color: &'static str) -> Self {
+        GColorValue::Color(AttrValue::from(color))
+    }
+}
+
+impl From<AttrValue> for GColorValue {
+    fn from(color: AttrValue) -> Self {
+        GColorValue::Color(color)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct GThemeOverrides {
+    pub mode: Option<GThemeMode>,
+    pub colors: Vec<(GColorRole, GColorValue)>,
+}
+
+impl GThemeOverrides {
+    pub fn mode(mut self, mode: GThemeMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn color(mut self, role: GColorRole, value: impl Into<GColorValue>) -> Self {
+        self.colors.push((role, value.into()));
+        self
+    }
+
+    pub fn apply(&self, theme: &GTheme) -> GTheme {
+        let mut theme = theme.clone();
+        if let Some(mode) = self.mode {
+            theme.mode = mode;
+        }
+        for colors in [&mut theme.light, &mut theme.dark] {
+            let source = colors.clone();
+            for (role, value) in &self.colors {
+                *colors.get_mut(*role) = match value {
+                    GColorValue::Role(from) => source.get(*from).clone(),
+                    GColorValue::Color(color) => color.clone(),
+                };
+            }
+        }
+        theme
+    }
+}
@@ -0,0 +1,346 @@
+//! # Color scheme generation
+//! builds Material 3 tonal palettes and color roles from one seed color.
+//!
+//! Tones are computed in CIE LCh color space: tone is the `L*` lightness (0 is black, 100 is white),
+//! hue and chroma are taken from the seed. The palettes follow the Material "tonal spot" scheme:
+//! - primary: seed hue, chroma at least 48
+//! - secondary: seed hue, chroma 16
+//! - tertiary: seed hue + 60°, chroma 24
+//! - neutral: seed hue, chroma 4
+//! - neutral variant: seed hue, chroma 8
+//! - error: hue and chroma of `#B3261E`
+//!
+//! Achromatic seeds (black, white and grays, chroma below 5) give a gray scheme: their hue is meaningless,
+//! so all palettes except error keep the chroma of the seed.
+//!
+//! If the chroma can not be displayed in sRGB for the requested tone, it is reduced until the color fits.
+//!
+//! ## Examples
+//! ```
+//! use yew_google_material::prelude::*;
+//!
+//! let scheme = GColorScheme::from_seed("#00696D").unwrap();
+//! let theme = GTheme { light: scheme.light, dark: scheme.dark, ..Default::default() };
+//! let tone_90 = GCorePalettes::from_seed("#00696D").unwrap().primary.tone(90);
+//! ```
+
+use std::str::FromStr;
+
+use color_art::{Color, ColorSpace};
+use yew::AttrValue;
+
+use super::GColorRoles;
+
+const ERROR_SEED: &str = "#B3261E";
+const ACHROMATIC_CHROMA: f64 = 5.0;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GTonalPalette {
+    pub hue: f64,
+    pub chroma: f64,
+}
+
+impl GTonalPalette {
+    pub fn new(hue: f64, chroma: f64) -> Self {
+        Self { hue: hue.rem_euclid(360.0), chroma: chroma.max(0.0) }
+    }
+
+    pub fn from_color(color: &Color) -> Self {
+        let (_, a, b) = srgb_to_lab(color);
+        Self::new(b.atan2(a).to_degrees(), (a * a + b * b).sqrt())
+    }
+
+    pub fn tone(&self, tone: u8) -> AttrValue {
+        AttrValue::from(self.tone_color(tone).hex())
+    }
+
+    pub fn tone_color(&self, tone: u8) -> Color {
+        let lightness = f64::from(tone.min(100));
+        if let Some(color) = lch_to_srgb(lightness, self.chroma, self.hue) {
+            return color;
+        }
+        // Keep hue and tone, search the biggest chroma which is still inside sRGB
+        let mut low = 0.0;
+        let mut high = self.chroma;
+        for _ in 0..24 {
+            let middle = (low + high) / 2.0;
+            if lch_to_srgb(lightness, middle, self.hue).is_some() {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        lch_to_srgb(lightness, low, self.hue).unwrap_or_else(|| {
+            let gray = (lab_lightness_to_srgb(lightness) * 255.0).round();
+            Color::new(gray, gray, gray, 1.0)
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GCorePalettes {
+    pub primary: GTonalPalette,
+    pub secondary: GTonalPalette,
+    pub tertiary: GTonalPalette,
+    pub neutral: GTonalPalette,
+    pub neutral_variant: GTonalPalette,
+    pub error: GTonalPalette,
+}
+
+impl GCorePalettes {
+    pub fn from_seed(seed: &str) -> Result<Self, color_art::Error> {
+        let seed = GTonalPalette::from_color(&Color::from_str(seed)?);
+        let error = GTonalPalette::from_color(&Color::from_str(ERROR_SEED)?);
+        if seed.chroma < ACHROMATIC_CHROMA {
+            let gray = GTonalPalette::new(seed.hue, seed.chroma);
+            return Ok(Self {
+                primary: gray,
+                secondary: gray,
+                tertiary: gray,
+                neutral: gray,
+                neutral_variant: gray,
+                error,
+            });
+        }
+        Ok(Self {
+            primary: GTonalPalette::new(seed.hue, seed.chroma.max(48.0)),
+            secondary: GTonalPalette::new(seed.hue, 16.0),
+            tertiary: GTonalPalette::new(seed.hue + 60.0, 24.0),
+            neutral: GTonalPalette::new(seed.hue, 4.0),
+            neutral_variant: GTonalPalette::new(seed.hue, 8.0),
+            error,
+        })
+    }
+
+    pub fn light(&self) -> GColorRoles {
+        let (p, s, t, n, nv, e) = (self.primary, self.secondary, self.tertiary, self.neutral, self.neutral_variant, self.error);
+        GColorRoles {
+            primary: p.tone(40),
+            on_primary: p.tone(100),
+            primary_container: p.tone(90),
+            on_primary_container: p.tone(10),
+            secondary: s.tone(40),
+            on_secondary: s.tone(100),
+            secondary_container: s.tone(90),
+            on_secondary_container: s.tone(10),
+            tertiary: t.tone(40),
+            on_tertiary: t.tone(100),
+            tertiary_container: t.tone(90),
+            on_tertiary_container: t.tone(10),
+            error: e.tone(40),
+            on_error: e.tone(100),
+            error_container: e.tone(90),
+            on_error_container: e.tone(10),
+            background: n.tone(99),
+            on_background: n.tone(10),
+            surface: n.tone(99),
+            on_surface: n.tone(10),
+            surface_variant: nv.tone(90),
+            on_surface_variant: nv.tone(30),
+            outline: nv.tone(50),
+            outline_variant: nv.tone(80),
+            shadow: n.tone(0),
+            scrim: n.tone(0),
+            inverse_surface: n.tone(20),
+            inverse_on_surface: n.tone(95),
+            inverse_primary: p.tone(80),
+        }
+    }
+
+    pub fn dark(&self) -> GColorRoles {
+        let (p, s, t, n, nv, e) = (self.primary, self.secondary, self.tertiary, self.neutral, self.neutral_variant, self.error);
+        GColorRoles {
+            primary: p.tone(80),
+            on_primary: p.tone(20),
+            primary_container: p.tone(30),
+            on_primary_container: p.tone(90),
+            secondary: s.tone(80),
+            on_secondary: s.tone(20),
+            secondary_container: s.tone(30),
+            on_secondary_container: s.tone(90),
+            tertiary: t.tone(80),
+            on_tertiary: t.tone(20),
+            tertiary_container: t.tone(30),
+            on_tertiary_container: t.tone(90),
+            error: e.tone(80),
+            on_error: e.tone(20),
+            error_container: e.tone(30),
+            on_error_container: e.tone(90),
+            background: n.tone(10),
+            on_background: n.tone(90),
+            surface: n.tone(10),
+            on_surface: n.tone(90),
+            surface_variant: nv.tone(30),
+            on_surface_variant: nv.tone(80),
+            outline: nv.tone(60),
+            outline_variant: nv.tone(30),
+            shadow: n.tone(0),
+            scrim: n.tone(0),
+            inverse_surface: n.tone(90),
+            inverse_on_surface: n.tone(20),
+            inverse_primary: p.tone(40),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GColorScheme {
+    pub light: GColorRoles,
+    pub dark: GColorRoles,
+}
+
+impl GColorScheme {
+    pub fn from_seed(seed: &str) -> Result<Self, color_art::Error> {
+        let palettes = GCorePalettes::from_seed(seed)?;
+        Ok(Self {
+            light: palettes.light(),
+            dark: palettes.dark(),
+        })
+    }
+}
+
+// D65 reference white
+const XN: f64 = 0.95047;
+const YN: f64 = 1.0;
+const ZN: f64 = 1.08883;
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+fn lab_f_inverse(t: f64) -> f64 {
+    if t.powi(3) > EPSILON {
+        t.powi(3)
+    } else {
+        (116.0 * t - 16.0) / KAPPA
+    }
+}
+
+fn lab_f(t: f64) -> f64 {
+    if t > EPSILON {
+        t.cbrt()
+    } else {
+        (KAPPA * t + 16.0) / 116.0
+    }
+}
+
+fn linearize(channel: f64) -> f64 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// CIE Lab with the same D65 white as `lch_to_srgb` (Lab of color-art uses D50, which shifts hues)
+fn srgb_to_lab(color: &Color) -> (f64, f64, f64) {
+    let rgb = color.vec_of(ColorSpace::RGB);
+    let (r, g, b) = (linearize(rgb[0] / 255.0), linearize(rgb[1] / 255.0), linearize(rgb[2] / 255.0));
+    let fx = lab_f((0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / XN);
+    let fy = lab_f((0.2126729 * r + 0.7151522 * g + 0.0721750 * b) / YN);
+    let fz = lab_f((0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / ZN);
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+fn lab_lightness_to_srgb(lightness: f64) -> f64 {
+    let y = if lightness > KAPPA * EPSILON {
+        ((lightness + 16.0) / 116.0).powi(3)
+    } else {
+        lightness / KAPPA
+    };
+    gamma(y).clamp(0.0, 1.0)
+}
+
+fn gamma(linear: f64) -> f64 {
+    if linear <= 0.0031308 {
+        12.92 * linear
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Returns `None` if the color is outside of sRGB gamut
+fn lch_to_srgb(lightness: f64, chroma: f64, hue: f64) -> Option<Color> {
+    let (a, b) = (chroma * hue.to_radians().cos(), chroma * hue.to_radians().sin());
+    let fy = (lightness + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let x = XN * lab_f_inverse(fx);
+    let y = YN * if lightness > KAPPA * EPSILON { fy.powi(3) } else { lightness / KAPPA };
+    let z = ZN * lab_f_inverse(fz);
+
+    let linear = [
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    ];
+    let mut rgb = [0.0; 3];
+    for (channel, linear) in rgb.iter_mut().zip(linear) {
+        let value = gamma(linear);
+        if !(-0.0001..=1.0001).contains(&value) {
+            return None;
+        }
+        *channel = (value.clamp(0.0, 1.0) * 255.0).round();
+    }
+    Some(Color::new(rgb[0], rgb[1], rgb[2], 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{contrast_ratio, MIN_CONTRAST_RATIO};
+
+    fn palette_of(color: &str) -> GTonalPalette {
+        GTonalPalette::from_color(&Color::from_str(color).unwrap())
+    }
+
+    #[test]
+    fn primary_keeps_seed_hue() {
+        let seed = palette_of("#6750A4");
+        let scheme = GColorScheme::from_seed("#6750A4").unwrap();
+        for primary in [&scheme.light.primary, &scheme.dark.primary] {
+            let hue_difference = (palette_of(primary).hue - seed.hue).abs();
+            assert!(hue_difference.min(360.0 - hue_difference) < 3.0, "{primary} has another hue than the seed");
+        }
+    }
+
+    #[test]
+    fn on_colors_are_readable() {
+        for seed in ["#6750A4", "#00696D", "#B3261E", "#FFDE3F", "#000000", "#FFFFFF", "#808080"] {
+            let scheme = GColorScheme::from_seed(seed).unwrap();
+            for roles in [&scheme.light, &scheme.dark] {
+                for (color, on_color) in [
+                    (&roles.primary, &roles.on_primary),
+                    (&roles.primary_container, &roles.on_primary_container),
+                    (&roles.secondary, &roles.on_secondary),
+                    (&roles.secondary_container, &roles.on_secondary_container),
+                    (&roles.tertiary, &roles.on_tertiary),
+                    (&roles.tertiary_container, &roles.on_tertiary_container),
+                    (&roles.error, &roles.on_error),
+                    (&roles.error_container, &roles.on_error_container),
+                    (&roles.surface, &roles.on_surface),
+                    (&roles.surface_variant, &roles.on_surface_variant),
+                    (&roles.inverse_surface, &roles.inverse_on_surface),
+                ] {
+                    let ratio = contrast_ratio(on_color, color).unwrap();
+                    assert!(ratio >= MIN_CONTRAST_RATIO, "{on_color} on {color} of seed {seed} has contrast {ratio}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn achromatic_seed_gives_gray_scheme() {
+        for seed in ["#000000", "#FFFFFF", "#808080"] {
+            let scheme = GColorScheme::from_seed(seed).unwrap();
+            for primary in [&scheme.light.primary, &scheme.dark.primary] {
+                assert!(palette_of(primary).chroma < ACHROMATIC_CHROMA, "{primary} of seed {seed} is not gray");
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_gamut_tones_are_clamped() {
+        let palette = GTonalPalette::new(140.0, 120.0);
+        assert_eq!(srgb_to_lab(&palette.tone_color(0)).0.round(), 0.0);
+        assert_eq!(srgb_to_lab(&palette.tone_color(100)).0.round(), 100.0);
+    }
+}
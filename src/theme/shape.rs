@@ -0,0 +1,226 @@
+//! # GShape
+//! is the Material 3 shape scale `https://m3.material.io/styles/shape/corner-radius-scale`.
+//!
+//! `GShapeCorner` is a token of the scale: none (0px), extra-small (4px), small (8px), medium (12px), large (16px), extra-large (28px) and full.
+//! Radii of the tokens are set in `GShapeScale` of `GTheme` and rendered as `--md-sys-shape-corner-*` custom properties.
+//!
+//! `GShape` sets a token for every corner and the corner family:
+//! - `GCornerFamily::Rounded`: corners are rounded with `border-radius`
+//! - `GCornerFamily::Cut`: corners are cut diagonally with `clip-path`. Box shadows and borders on the cut edges are clipped too,
+//!   so cut shapes look better with Filled or Text buttons. `Full` cut corner is half of the side.
+//!
+//! Corners are logical: `top_start` is the top left corner in left-to-right layout and the top right corner in right-to-left layout.
+//! Rounded corners follow the text direction of the page. Cut corners are mirrored if `direction` of `GTheme` is `GDirection::Rtl`
+//! (`clip-path` can not follow the direction itself), with `GDirection::Auto` they are drawn left-to-right.
+//!
+//! `GButton` uses `GShapeCorner::Full` by default, `GTextInput` uses `GShapeCorner::ExtraSmall`
+//! (only top corners for `GInputStyle::Filled`).
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! <GButton id="medium_button" label="Medium" shape={GShapeCorner::Medium} />
+//! <GButton id="cut_button" label="Cut" shape={GShape::cut(GShapeCorner::Small)} />
+//! <GButton
+//!     id="leaf_button"
+//!     label="Leaf"
+//!     shape={GShape::rounded(GShapeCorner::None).top_start(GShapeCorner::Large).bottom_end(GShapeCorner::Large)}
+//! />
+//! ```
+
+use yew::html::IntoPropValue;
+use yew::AttrValue;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "kebab-case"))]
+pub enum GShapeCorner {
+    None,
+    ExtraSmall,
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+    #[default]
+    Full,
+}
+
+impl GShapeCorner {
+    pub const ALL: [GShapeCorner; 7] = [
+        GShapeCorner::None,
+        GShapeCorner::ExtraSmall,
+        GShapeCorner::Small,
+        GShapeCorner::Medium,
+        GShapeCorner::Large,
+        GShapeCorner::ExtraLarge,
+        GShapeCorner::Full,
+    ];
+
+    /// Name in kebab-case, as in `--md-sys-shape-corner-*` tokens
+    pub fn name(&self) -> &'static str {
+        match self {
+            GShapeCorner::None => "none",
+            GShapeCorner::ExtraSmall => "extra-small",
+            GShapeCorner::Small => "small",
+            GShapeCorner::Medium => "medium",
+            GShapeCorner::Large => "large",
+            GShapeCorner::ExtraLarge => "extra-large",
+            GShapeCorner::Full => "full",
+        }
+    }
+
+    /// `var(--md-sys-shape-corner-{name}, {baseline radius})`
+    pub fn var(&self) -> AttrValue {
+        AttrValue::from(format!("var(--md-sys-shape-corner-{}, {})", self.name(), GShapeScale::default().radius(*self)))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "camelCase", default))]
+pub struct GShapeScale {
+    pub none: AttrValue,
+    pub extra_small: AttrValue,
+    pub small: AttrValue,
+    pub medium: AttrValue,
+    pub large: AttrValue,
+    pub extra_large: AttrValue,
+    pub full: AttrValue,
+}
+
+impl Default for GShapeScale {
+    fn default() -> Self {
+        Self {
+            none: AttrValue::from("0px"),
+            extra_small: AttrValue::from("4px"),
+            small: AttrValue::from("8px"),
+            medium: AttrValue::from("12px"),
+            large: AttrValue::from("16px"),
+            extra_large: AttrValue::from("28px"),
+            full: AttrValue::from("9999px"),
+        }
+    }
+}
+
+impl GShapeScale {
+    pub fn radius(&self, corner: GShapeCorner) -> &AttrValue {
+        match corner {
+            GShapeCorner::None => &self.none,
+            GShapeCorner::ExtraSmall => &self.extra_small,
+            GShapeCorner::Small => &self.small,
+            GShapeCorner::Medium => &self.medium,
+            GShapeCorner::Large => &self.large,
+            GShapeCorner::ExtraLarge => &self.extra_large,
+            GShapeCorner::Full => &self.full,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GCornerFamily {
+    #[default]
+    Rounded,
+    Cut,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct GShape {
+    pub top_start: GShapeCorner,
+    pub top_end: GShapeCorner,
+    pub bottom_end: GShapeCorner,
+    pub bottom_start: GShapeCorner,
+    pub family: GCornerFamily,
+}
+
+impl From<GShapeCorner> for GShape {
+    fn from(corner: GShapeCorner) -> Self {
+        GShape::rounded(corner)
+    }
+}
+
+impl IntoPropValue<GShape> for GShapeCorner {
+    fn into_prop_value(self) -> GShape {
+        GShape::from(self)
+    }
+}
+
+impl GShape {
+    pub fn rounded(corner: GShapeCorner) -> Self {
+        Self {
+            top_start: corner,
+            top_end: corner,
+            bottom_end: corner,
+            bottom_start: corner,
+            family: GCornerFamily::Rounded,
+        }
+    }
+
+    pub fn cut(corner: GShapeCorner) -> Self {
+        Self {
+            family: GCornerFamily::Cut,
+            ..GShape::rounded(corner)
+        }
+    }
+
+    pub fn top_start(mut self, corner: GShapeCorner) -> Self {
+        self.top_start = corner;
+        self
+    }
+
+    pub fn top_end(mut self, corner: GShapeCorner) -> Self {
+        self.top_end = corner;
+        self
+    }
+
+    pub fn bottom_end(mut self, corner: GShapeCorner) -> Self {
+        self.bottom_end = corner;
+        self
+    }
+
+    pub fn bottom_start(mut self, corner: GShapeCorner) -> Self {
+        self.bottom_start = corner;
+        self
+    }
+
+    pub fn top(self, corner: GShapeCorner) -> Self {
+        self.top_start(corner).top_end(corner)
+    }
+
+    pub fn bottom(self, corner: GShapeCorner) -> Self {
+        self.bottom_start(corner).bottom_end(corner)
+    }
+
+    /// Css declarations of logical `border-*-radius` properties (or `clip-path` for cut corners) which follow `GThemeProvider`
+    pub fn css(&self) -> String {
+        match self.family {
+            GCornerFamily::Rounded => format!(
+                "border-start-start-radius: {}; border-start-end-radius: {}; border-end-end-radius: {}; border-end-start-radius: {};",
+                self.top_start.var(),
+                self.top_end.var(),
+                self.bottom_end.var(),
+                self.bottom_start.var(),
+            ),
+            GCornerFamily::Cut => {
+                let cut = |corner: GShapeCorner| format!("min({}, 50%)", corner.var());
+                let (top_start, top_end, bottom_end, bottom_start) =
+                    (cut(self.top_start), cut(self.top_end), cut(self.bottom_end), cut(self.bottom_start));
+                // clip-path has no logical form, x is mirrored with `--md-sys-direction-sign` (-1 in right-to-left theme)
+                let x = |x: String| format!("calc(50% + var(--md-sys-direction-sign, 1) * ({x} - 50%))");
+                format!(
+                    "border-radius: 0; clip-path: polygon(\
+                    {} 0, {} 0, {} {top_end}, \
+                    {} calc(100% - {bottom_end}), {} 100%, \
+                    {} 100%, {} calc(100% - {bottom_start}), {} {top_start});",
+                    x(top_start.clone()),
+                    x(format!("100% - {top_end}")),
+                    x(String::from("100%")),
+                    x(String::from("100%")),
+                    x(format!("100% - {bottom_end}")),
+                    x(bottom_start.clone()),
+                    x(String::from("0%")),
+                    x(String::from("0%")),
+                )
+            },
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! # GStateLayer
+//! is the Material 3 state layer `https://m3.material.io/foundations/interaction/states/state-layers`.
+//!
+//! Interaction states are shown with an overlay of the content color (the "on" color of the container) with fixed opacity:
+//! - `GState::Hover`: 8%
+//! - `GState::Focus`: 10%
+//! - `GState::Pressed`: 10%
+//! - `GState::Dragged`: 16%
+//!
+//! `GButton` uses the label color as the state layer color, filled `GTextInput` uses `on_surface`.
+//! The result is a css `color-mix()` value, so colors can be any css colors including `var(--md-sys-color-*)` and `currentColor`.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let state_layer = GStateLayer::new("var(--md-sys-color-on-primary-container)");
+//! // color for an opaque container
+//! let hover_background = state_layer.over("var(--md-sys-color-primary-container)", GState::Hover);
+//! // transparent overlay for a transparent container
+//! let pressed_background = state_layer.overlay(GState::Pressed);
+//! ```
+
+use yew::AttrValue;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GState {
+    Hover,
+    Focus,
+    Pressed,
+    Dragged,
+}
+
+impl GState {
+    pub fn opacity(&self) -> f64 {
+        match self {
+            GState::Hover => 0.08,
+            GState::Focus => 0.10,
+            GState::Pressed => 0.10,
+            GState::Dragged => 0.16,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GStateLayer {
+    color: AttrValue,
+}
+
+impl GStateLayer {
+    pub fn new(color: &str) -> Self {
+        Self {
+            color: AttrValue::from(color.to_string()),
+        }
+    }
+
+    /// Semi-transparent state layer color, use it above transparent containers
+    pub fn overlay(&self, state: GState) -> AttrValue {
+        self.with_opacity(state.opacity())
+    }
+
+    /// Semi-transparent color with any opacity, e.g. 0.12 for disabled containers and 0.38 for disabled content
+    pub fn with_opacity(&self, opacity: f64) -> AttrValue {
+        AttrValue::from(format!("color-mix(in srgb, {} {}%, transparent)", self.color, opacity * 100.0))
+    }
+
+    /// Color of the `container` covered with the state layer
+    pub fn over(&self, container: &str, state: GState) -> AttrValue {
+        AttrValue::from(format!("color-mix(in srgb, {} {}%, {container})", self.color, state.opacity() * 100.0))
+    }
+}
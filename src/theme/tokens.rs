@@ -0,0 +1,69 @@
+//! # CSS custom properties
+//! `--md-sys-*` tokens rendered by `GThemeProvider` and used by components.
+//! Components use `var()` with Material 3 baseline values as fallback, so they also work without a provider.
+
+use yew::AttrValue;
+
+use super::{GColorRoles, GDuration, GEasing, GElevation, GShapeCorner, GTheme, GTypescale};
+
+const ELEVATIONS: [GElevation; 6] = [
+    GElevation::Level0,
+    GElevation::Level1,
+    GElevation::Level2,
+    GElevation::Level3,
+    GElevation::Level4,
+    GElevation::Level5,
+];
+
+impl GTheme {
+    /// Declarations of all `--md-sys-*` custom properties of the active mode, e.g. for `style` attribute
+    pub fn css_variables(&self) -> String {
+        let mut css = String::new();
+        for (role, value) in self.colors().roles() {
+            css.push_str(&format!("--md-sys-color-{role}: {value};"));
+        }
+        css.push_str(&format!("--md-sys-color-surface-tint: {};", self.colors().primary));
+        for (level, elevation) in ELEVATIONS.iter().enumerate() {
+            let tint = if self.is_dark() { elevation.surface_tint_opacity() * 100.0 } else { 0.0 };
+            css.push_str(&format!("--md-sys-elevation-level{level}: {};", elevation.box_shadow(self)));
+            css.push_str(&format!("--md-sys-elevation-tint-level{level}: {tint}%;"));
+        }
+        for corner in GShapeCorner::ALL {
+            css.push_str(&format!("--md-sys-shape-corner-{}: {};", corner.name(), self.shape.radius(corner)));
+        }
+        for easing in GEasing::ALL {
+            css.push_str(&format!("--md-sys-motion-easing-{}: {};", easing.name(), easing.cubic_bezier()));
+        }
+        for duration in GDuration::ALL {
+            let millis = if self.reduce_motion { 0 } else { duration.millis() };
+            css.push_str(&format!("--md-sys-motion-duration-{}: {millis}ms;", duration.name()));
+        }
+        css.push_str(&format!("--md-sys-density-scale: {};", self.density.scale()));
+        css.push_str(&format!("--md-ref-typeface-brand: {};", self.typography.brand));
+        css.push_str(&format!("--md-ref-typeface-plain: {};", self.typography.plain));
+        for scale in GTypescale::ALL {
+            let font = self.typography.font(scale);
+            let style = self.typography.style(scale);
+            css.push_str(&format!(
+                "--md-sys-typescale-{role}-font: {font};\
+                --md-sys-typescale-{role}-size: {size};\
+                --md-sys-typescale-{role}-line-height: {line_height};\
+                --md-sys-typescale-{role}-weight: {weight};\
+                --md-sys-typescale-{role}-tracking: {tracking};",
+                role = scale.name(),
+                size = style.size,
+                line_height = style.line_height,
+                weight = style.weight,
+                tracking = style.tracking,
+            ));
+        }
+        css
+    }
+}
+
+/// `var(--md-sys-color-{role}, {baseline light value})`
+pub(crate) fn sys_color(role: &str) -> AttrValue {
+    let baseline = GColorRoles::default();
+    let fallback = baseline.role(role).map(AttrValue::as_str).unwrap_or("currentColor");
+    AttrValue::from(format!("var(--md-sys-color-{role}, {fallback})"))
+}
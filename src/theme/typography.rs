@@ -0,0 +1,236 @@
+//! # GTypescale
+//! is the Material 3 type scale `https://m3.material.io/styles/typography/type-scale-tokens`.
+//!
+//! Roles: display, headline, title, body and label, each in large, medium and small size.
+//! Display, headline and `TitleLarge` use the brand typeface of `GTypography`, all other roles use the plain typeface.
+//! Size, line height, weight and tracking of every role are `GTypescaleStyle` fields of `GTypography` (Material 3 baseline by default).
+//!
+//! `GButton` uses `LabelLarge`, `GTextInput` uses `BodyLarge` (text and label) and `BodySmall` (supporting text).
+//! Use `GText` to render your own text in any role.
+//!
+//! ## Examples
+//! ```
+//! use yew::prelude::*;
+//! use yew_google_material::prelude::*;
+//!
+//! let theme = GTheme {
+//!     typography: GTypography {
+//!         brand: AttrValue::from("'Open Sans', sans-serif"),
+//!         display_large: GTypescaleStyle { size: AttrValue::from("64px"), line_height: AttrValue::from("72px"), ..GTypescaleStyle::baseline(GTypescale::DisplayLarge) },
+//!         ..GTypography::default()
+//!     },
+//!     ..GTheme::default()
+//! };
+//! // css declarations for your own elements
+//! let title_style = GTypescale::TitleLarge.css();
+//! ```
+
+use yew::AttrValue;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "camelCase", default))]
+pub struct GTypography {
+    /// Typeface of display, headline and large title roles
+    pub brand: AttrValue,
+    /// Typeface of all other roles
+    pub plain: AttrValue,
+    pub display_large: GTypescaleStyle,
+    pub display_medium: GTypescaleStyle,
+    pub display_small: GTypescaleStyle,
+    pub headline_large: GTypescaleStyle,
+    pub headline_medium: GTypescaleStyle,
+    pub headline_small: GTypescaleStyle,
+    pub title_large: GTypescaleStyle,
+    pub title_medium: GTypescaleStyle,
+    pub title_small: GTypescaleStyle,
+    pub body_large: GTypescaleStyle,
+    pub body_medium: GTypescaleStyle,
+    pub body_small: GTypescaleStyle,
+    pub label_large: GTypescaleStyle,
+    pub label_medium: GTypescaleStyle,
+    pub label_small: GTypescaleStyle,
+}
+
+impl Default for GTypography {
+    fn default() -> Self {
+        Self {
+            brand: AttrValue::from("Roboto, sans-serif"),
+            plain: AttrValue::from("Roboto, sans-serif"),
+            display_large: GTypescaleStyle::baseline(GTypescale::DisplayLarge),
+            display_medium: GTypescaleStyle::baseline(GTypescale::DisplayMedium),
+            display_small: GTypescaleStyle::baseline(GTypescale::DisplaySmall),
+            headline_large: GTypescaleStyle::baseline(GTypescale::HeadlineLarge),
+            headline_medium: GTypescaleStyle::baseline(GTypescale::HeadlineMedium),
+            headline_small: GTypescaleStyle::baseline(GTypescale::HeadlineSmall),
+            title_large: GTypescaleStyle::baseline(GTypescale::TitleLarge),
+            title_medium: GTypescaleStyle::baseline(GTypescale::TitleMedium),
+            title_small: GTypescaleStyle::baseline(GTypescale::TitleSmall),
+            body_large: GTypescaleStyle::baseline(GTypescale::BodyLarge),
+            body_medium: GTypescaleStyle::baseline(GTypescale::BodyMedium),
+            body_small: GTypescaleStyle::baseline(GTypescale::BodySmall),
+            label_large: GTypescaleStyle::baseline(GTypescale::LabelLarge),
+            label_medium: GTypescaleStyle::baseline(GTypescale::LabelMedium),
+            label_small: GTypescaleStyle::baseline(GTypescale::LabelSmall),
+        }
+    }
+}
+
+impl GTypography {
+    pub fn style(&self, scale: GTypescale) -> &GTypescaleStyle {
+        match scale {
+            GTypescale::DisplayLarge => &self.display_large,
+            GTypescale::DisplayMedium => &self.display_medium,
+            GTypescale::DisplaySmall => &self.display_small,
+            GTypescale::HeadlineLarge => &self.headline_large,
+            GTypescale::HeadlineMedium => &self.headline_medium,
+            GTypescale::HeadlineSmall => &self.headline_small,
+            GTypescale::TitleLarge => &self.title_large,
+            GTypescale::TitleMedium => &self.title_medium,
+            GTypescale::TitleSmall => &self.title_small,
+            GTypescale::BodyLarge => &self.body_large,
+            GTypescale::BodyMedium => &self.body_medium,
+            GTypescale::BodySmall => &self.body_small,
+            GTypescale::LabelLarge => &self.label_large,
+            GTypescale::LabelMedium => &self.label_medium,
+            GTypescale::LabelSmall => &self.label_small,
+        }
+    }
+
+    pub fn font(&self, scale: GTypescale) -> &AttrValue {
+        if scale.is_brand() { &self.brand } else { &self.plain }
+    }
+}
+
+/// Size, line height, weight and tracking of one type scale role
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "camelCase"))]
+pub struct GTypescaleStyle {
+    pub size: AttrValue,
+    pub line_height: AttrValue,
+    pub weight: AttrValue,
+    pub tracking: AttrValue,
+}
+
+impl GTypescaleStyle {
+    /// Material 3 baseline values of the role
+    pub fn baseline(scale: GTypescale) -> Self {
+        Self {
+            size: AttrValue::from(scale.size()),
+            line_height: AttrValue::from(scale.line_height()),
+            weight: AttrValue::from(scale.weight()),
+            tracking: AttrValue::from(scale.tracking()),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GTypescale {
+    DisplayLarge,
+    DisplayMedium,
+    DisplaySmall,
+    HeadlineLarge,
+    HeadlineMedium,
+    HeadlineSmall,
+    TitleLarge,
+    TitleMedium,
+    TitleSmall,
+    BodyLarge,
+    #[default]
+    BodyMedium,
+    BodySmall,
+    LabelLarge,
+    LabelMedium,
+    LabelSmall,
+}
+
+impl GTypescale {
+    pub const ALL: [GTypescale; 15] = [
+        GTypescale::DisplayLarge,
+        GTypescale::DisplayMedium,
+        GTypescale::DisplaySmall,
+        GTypescale::HeadlineLarge,
+        GTypescale::HeadlineMedium,
+        GTypescale::HeadlineSmall,
+        GTypescale::TitleLarge,
+        GTypescale::TitleMedium,
+        GTypescale::TitleSmall,
+        GTypescale::BodyLarge,
+        GTypescale::BodyMedium,
+        GTypescale::BodySmall,
+        GTypescale::LabelLarge,
+        GTypescale::LabelMedium,
+        GTypescale::LabelSmall,
+    ];
+
+    /// Name in kebab-case, as in `--md-sys-typescale-*` tokens
+    pub fn name(&self) -> &'static str {
+        self.tokens().0
+    }
+
+    /// Baseline font size, the value of the theme is in `GTypography`
+    pub fn size(&self) -> &'static str {
+        self.tokens().1
+    }
+
+    pub fn line_height(&self) -> &'static str {
+        self.tokens().2
+    }
+
+    pub fn weight(&self) -> &'static str {
+        self.tokens().3
+    }
+
+    pub fn tracking(&self) -> &'static str {
+        self.tokens().4
+    }
+
+    pub fn is_brand(&self) -> bool {
+        *self as u8 <= GTypescale::TitleLarge as u8
+    }
+
+    // (name, size, line height, weight, tracking)
+    fn tokens(&self) -> (&'static str, &'static str, &'static str, &'static str, &'static str) {
+        match self {
+            GTypescale::DisplayLarge => ("display-large", "57px", "64px", "400", "-0.25px"),
+            GTypescale::DisplayMedium => ("display-medium", "45px", "52px", "400", "0px"),
+            GTypescale::DisplaySmall => ("display-small", "36px", "44px", "400", "0px"),
+            GTypescale::HeadlineLarge => ("headline-large", "32px", "40px", "400", "0px"),
+            GTypescale::HeadlineMedium => ("headline-medium", "28px", "36px", "400", "0px"),
+            GTypescale::HeadlineSmall => ("headline-small", "24px", "32px", "400", "0px"),
+            GTypescale::TitleLarge => ("title-large", "22px", "28px", "400", "0px"),
+            GTypescale::TitleMedium => ("title-medium", "16px", "24px", "500", "0.15px"),
+            GTypescale::TitleSmall => ("title-small", "14px", "20px", "500", "0.1px"),
+            GTypescale::BodyLarge => ("body-large", "16px", "24px", "400", "0.5px"),
+            GTypescale::BodyMedium => ("body-medium", "14px", "20px", "400", "0.25px"),
+            GTypescale::BodySmall => ("body-small", "12px", "16px", "400", "0.4px"),
+            GTypescale::LabelLarge => ("label-large", "14px", "20px", "500", "0.1px"),
+            GTypescale::LabelMedium => ("label-medium", "12px", "16px", "500", "0.5px"),
+            GTypescale::LabelSmall => ("label-small", "11px", "16px", "500", "0.5px"),
+        }
+    }
+
+    /// `var(--md-sys-typescale-{role}-{token}, {baseline value})`, token is one of `font`, `size`, `line-height`, `weight`, `tracking`
+    pub fn var(&self, token: &str) -> AttrValue {
+        let fallback = match token {
+            "font" => "Roboto, sans-serif",
+            "size" => self.size(),
+            "line-height" => self.line_height(),
+            "weight" => self.weight(),
+            "tracking" => self.tracking(),
+            _ => "inherit",
+        };
+        AttrValue::from(format!("var(--md-sys-typescale-{}-{token}, {fallback})", self.name()))
+    }
+
+    /// Css declarations of `font-family`, `font-size`, `line-height`, `font-weight` and `letter-spacing` which follow `GThemeProvider`
+    pub fn css(&self) -> String {
+        format!(
+            "font-family: {}; font-size: {}; line-height: {}; font-weight: {}; letter-spacing: {};",
+            self.var("font"),
+            self.var("size"),
+            self.var("line-height"),
+            self.var("weight"),
+            self.var("tracking"),
+        )
+    }
+}